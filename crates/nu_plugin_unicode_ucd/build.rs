@@ -7,9 +7,12 @@ use std::{
     time::SystemTime,
 };
 
-use ucd_parse::{Codepoint, UcdFile, UcdFileByCodepoint};
+use ucd_parse::{Codepoint, Codepoints, UcdFile, UcdFileByCodepoint};
 
-use crate::build_types::name_aliases::NameAliasLiteral;
+use crate::build_types::{
+    casing::{CaseFoldingLiteral, SpecialCasingLiteral},
+    name_aliases::NameAliasLiteral,
+};
 
 pub mod build_types;
 
@@ -18,6 +21,32 @@ fn main() {
     let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
     generate_unicode_data(&ucd_dir, &out_dir);
     generate_name_aliases(&ucd_dir, &out_dir);
+    generate_jamo_short_names(&ucd_dir, &out_dir);
+    generate_name_patterns(&ucd_dir, &out_dir);
+    generate_named_sequences(&ucd_dir, &out_dir);
+    generate_names_list(&ucd_dir, &out_dir);
+    generate_blocks(&ucd_dir, &out_dir);
+    generate_scripts(&ucd_dir, &out_dir);
+    generate_script_extensions(&ucd_dir, &out_dir);
+    generate_property_value_aliases(&ucd_dir, &out_dir);
+    generate_ages(&ucd_dir, &out_dir);
+    generate_east_asian_widths(&ucd_dir, &out_dir);
+    generate_emoji_properties(&ucd_dir, &out_dir);
+    generate_emoji_variation_sequences(&ucd_dir, &out_dir);
+    generate_grapheme_cluster_breaks(&ucd_dir, &out_dir);
+    generate_indic_conjunct_breaks(&ucd_dir, &out_dir);
+    generate_binary_properties(&ucd_dir, &out_dir);
+    generate_composition_exclusions(&ucd_dir, &out_dir);
+    generate_quick_checks(&ucd_dir, &out_dir);
+    generate_word_breaks(&ucd_dir, &out_dir);
+    generate_sentence_breaks(&ucd_dir, &out_dir);
+    generate_line_breaks(&ucd_dir, &out_dir);
+    generate_bidi_classes(&ucd_dir, &out_dir);
+    generate_bidi_mirroring(&ucd_dir, &out_dir);
+    generate_bidi_brackets(&ucd_dir, &out_dir);
+    generate_special_casings(&ucd_dir, &out_dir);
+    generate_case_foldings(&ucd_dir, &out_dir);
+    generate_nfkc_casefolds(&ucd_dir, &out_dir);
 }
 
 fn get_codegen_file_time() -> io::Result<SystemTime> {
@@ -77,6 +106,165 @@ fn generate_ucd_source<U, F>(
     phf_source_file.set_modified(out_mtime).unwrap();
 }
 
+fn generate_ucd_table_source<U, F>(
+    ucd_dir: &Path,
+    out_path: &Path,
+    out_mtime: SystemTime,
+    generate_table: F,
+) where
+    U: UcdFile,
+    F: Fn(BufWriter<&mut File>, Vec<U>),
+{
+    let mut source_file = File::create(out_path).unwrap();
+    let writer = BufWriter::new(&mut source_file);
+    let parsed = ucd_parse::parse::<_, U>(ucd_dir).unwrap();
+
+    println!(
+        "cargo::rerun-if-changed={}",
+        U::file_path(ucd_dir).to_str().unwrap()
+    );
+    println!("cargo::rerun-if-changed={}", out_path.to_str().unwrap());
+
+    generate_table(writer, parsed);
+    source_file.set_modified(out_mtime).unwrap();
+}
+
+/// Like [`generate_ucd_table_source`], but for UCD files which ucd-parse does
+/// not support. Each line is passed along as its `;`-separated fields, with
+/// comments and blank lines dropped.
+fn generate_ucd_fields_source<F>(
+    ucd_dir: &Path,
+    relative_path: &str,
+    out_path: &Path,
+    out_mtime: SystemTime,
+    generate_table: F,
+) where
+    F: Fn(BufWriter<&mut File>, Vec<Vec<String>>),
+{
+    let mut source_file = File::create(out_path).unwrap();
+    let writer = BufWriter::new(&mut source_file);
+    let ucd_path = ucd_dir.join(relative_path);
+
+    let parsed = fs::read_to_string(&ucd_path)
+        .unwrap()
+        .lines()
+        .filter_map(|line| {
+            let line = line.split('#').next().unwrap().trim();
+
+            (!line.is_empty()).then(|| {
+                line.split(';')
+                    .map(|field| field.trim().to_owned())
+                    .collect()
+            })
+        })
+        .collect();
+
+    println!("cargo::rerun-if-changed={}", ucd_path.to_str().unwrap());
+    println!("cargo::rerun-if-changed={}", out_path.to_str().unwrap());
+
+    generate_table(writer, parsed);
+    source_file.set_modified(out_mtime).unwrap();
+}
+
+/// Parses a space separated sequence of hex codepoints, as used in the
+/// fields of many UCD files.
+fn parse_codepoint_sequence(field: &str) -> Vec<u32> {
+    field
+        .split_whitespace()
+        .map(|cp| u32::from_str_radix(cp, 16).unwrap())
+        .collect()
+}
+
+/// Parses a single codepoint or a `start..end` codepoint range, as used in
+/// the first field of many UCD files.
+fn parse_codepoint_range(field: &str) -> (u32, u32) {
+    match field.split_once("..") {
+        Some((start, end)) => (
+            u32::from_str_radix(start, 16).unwrap(),
+            u32::from_str_radix(end, 16).unwrap(),
+        ),
+        None => {
+            let cp = u32::from_str_radix(field, 16).unwrap();
+            (cp, cp)
+        }
+    }
+}
+
+/// Writes a `RangeTable` static named `name` from the given
+/// `(start, end, value literal)` entries.
+fn write_range_table<W: Write>(
+    writer: &mut W,
+    name: &str,
+    value_type: &str,
+    entries: impl IntoIterator<Item = (u32, u32, String)>,
+) {
+    writeln!(
+        writer,
+        "pub static {name}: RangeTable<{value_type}> = {};\n",
+        range_table_literal(name, entries)
+    )
+    .unwrap();
+}
+
+/// Builds a `RangeTable` expression from the given
+/// `(start, end, value literal)` entries. The entries are sorted, and adjacent
+/// ranges with the same value are merged into one.
+fn range_table_literal(
+    name: &str,
+    entries: impl IntoIterator<Item = (u32, u32, String)>,
+) -> String {
+    let mut entries: Vec<_> = entries.into_iter().collect();
+    entries.sort_by_key(|(start, _, _)| *start);
+
+    let mut merged: Vec<(u32, u32, String)> = Vec::new();
+
+    for (start, end, value) in entries {
+        match merged.last_mut() {
+            Some((_, last_end, last_value)) if *last_end + 1 == start && *last_value == value => {
+                *last_end = end;
+            }
+            Some((_, last_end, _)) => {
+                assert!(
+                    *last_end < start,
+                    "{name}: unsorted or overlapping range at {start:X}"
+                );
+                merged.push((start, end, value));
+            }
+            None => merged.push((start, end, value)),
+        }
+    }
+
+    let mut literal = String::from("RangeTable(&[\n");
+
+    for (start, end, value) in merged {
+        literal.push_str(&format!("    ({start:#X}, {end:#X}, {value}),\n"));
+    }
+
+    literal.push_str("])");
+    literal
+}
+
+/// Converts a property value from a UCD file into the name of the
+/// corresponding enum variant, e.g. `Regional_Indicator` to
+/// `RegionalIndicator` and `LVT` to `Lvt`.
+fn enum_variant(value: &str) -> String {
+    value
+        .split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            let first = chars.next().unwrap().to_ascii_uppercase();
+
+            if part.chars().all(|ch| ch.is_ascii_uppercase()) {
+                std::iter::once(first)
+                    .chain(chars.map(|ch| ch.to_ascii_lowercase()))
+                    .collect::<String>()
+            } else {
+                std::iter::once(first).chain(chars).collect()
+            }
+        })
+        .collect()
+}
+
 fn generate_unicode_data(ucd_dir: &Path, out_dir: &Path) {
     let codegen_file_time = get_codegen_file_time().unwrap();
 
@@ -84,11 +272,38 @@ fn generate_unicode_data(ucd_dir: &Path, out_dir: &Path) {
         ucd_dir,
         &out_dir.join("unicode_data.rs"),
         codegen_file_time,
-        |mut writer, parsed, mut phf_source| {
+        |mut writer, parsed: BTreeMap<Codepoint, Vec<ucd_parse::UnicodeData>>, mut phf_source| {
+            let mut ranges = Vec::new();
+            let mut range_start = None;
+
             for (codepoint, mut data) in parsed.into_iter() {
                 assert_eq!(1, data.len());
                 let data = data.pop().unwrap();
 
+                // Large blocks are encoded as a pair of `<..., First>` and
+                // `<..., Last>` rows which share all of their properties, so
+                // these go into a range table instead of the map.
+                if data.is_range_start() {
+                    assert!(range_start.is_none(), "nested range at {codepoint}");
+                    range_start = Some(data);
+                    continue;
+                }
+
+                if data.is_range_end() {
+                    // the names of these are derived on lookup
+                    let mut start = range_start.take().expect("range end without start");
+                    start.name = String::new();
+
+                    ranges.push(format!(
+                        "({:#X}, {:#X}, &{:?})",
+                        start.codepoint.value(),
+                        codepoint.value(),
+                        build_types::UnicodeDataLiteral(start)
+                    ));
+
+                    continue;
+                }
+
                 phf_source.entry(
                     codepoint.value(),
                     format!("&{:?}", build_types::UnicodeDataLiteral(data)),
@@ -107,6 +322,13 @@ fn generate_unicode_data(ucd_dir: &Path, out_dir: &Path) {
                 phf_source.build()
             )
             .unwrap();
+
+            writeln!(
+                &mut writer,
+                "pub static UNICODE_DATA_RANGES: RangeTable<&UnicodeDataStatic> = RangeTable(&[\n{}\n]);\n",
+                ranges.join(",\n")
+            )
+            .unwrap();
         },
     );
 }
@@ -135,3 +357,777 @@ fn generate_name_aliases(ucd_dir: &Path, out_dir: &Path) {
         },
     )
 }
+
+fn generate_jamo_short_names(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    generate_ucd_source(
+        ucd_dir,
+        &out_dir.join("jamo_short_names.rs"),
+        codegen_file_time,
+        |mut writer, parsed: BTreeMap<Codepoint, Vec<ucd_parse::JamoShortName>>, mut phf_source| {
+            for (codepoint, mut names) in parsed.into_iter() {
+                assert_eq!(1, names.len());
+                phf_source.entry(
+                    codepoint.value(),
+                    format!("{:?}", names.pop().unwrap().name),
+                );
+            }
+
+            writeln!(
+                &mut writer,
+                "pub static JAMO_SHORT_NAMES: phf::Map<u32, &str> = {};\n",
+                phf_source.build()
+            )
+            .unwrap();
+        },
+    )
+}
+
+fn generate_name_patterns(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    generate_ucd_table_source(
+        ucd_dir,
+        &out_dir.join("name_patterns.rs"),
+        codegen_file_time,
+        |mut writer, parsed: Vec<ucd_parse::extracted::DerivedName>| {
+            // Only the NR2 patterns are kept; every other name is either in
+            // `UnicodeData.txt` or derived from the Hangul jamo.
+            let patterns = parsed.into_iter().filter_map(|row| {
+                let prefix = row.name.strip_suffix('*')?;
+                let (start, end) = codepoints_bounds(row.codepoints);
+
+                Some((start, end, format!("{prefix:?}")))
+            });
+
+            write_range_table(&mut writer, "NAME_PATTERNS", "&str", patterns);
+        },
+    )
+}
+
+fn codepoints_bounds(codepoints: Codepoints) -> (u32, u32) {
+    match codepoints {
+        Codepoints::Single(cp) => (cp.value(), cp.value()),
+        Codepoints::Range(range) => (range.start.value(), range.end.value()),
+    }
+}
+
+fn generate_named_sequences(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    generate_ucd_fields_source(
+        ucd_dir,
+        "NamedSequences.txt",
+        &out_dir.join("named_sequences.rs"),
+        codegen_file_time,
+        |mut writer, parsed| {
+            writeln!(
+                &mut writer,
+                "pub static NAMED_SEQUENCES: &[(&str, &[u32])] = &["
+            )
+            .unwrap();
+
+            for fields in parsed {
+                writeln!(
+                    &mut writer,
+                    "    ({:?}, &{:?}),",
+                    fields[0],
+                    parse_codepoint_sequence(&fields[1])
+                )
+                .unwrap();
+            }
+
+            writeln!(&mut writer, "];\n").unwrap();
+        },
+    )
+}
+
+fn generate_names_list(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+    let ucd_path = ucd_dir.join("NamesList.txt");
+    let out_path = out_dir.join("names_list.rs");
+
+    let mut source_file = File::create(&out_path).unwrap();
+    let mut writer = BufWriter::new(&mut source_file);
+    let mut phf_source = phf_codegen::Map::<u32>::new();
+
+    // The names list is meant for human readers, so only the parts that are
+    // reliably structured are kept: entry lines start with the codepoint and
+    // a tab, and annotations of an entry are indented by a tab and start with
+    // a marker character.
+    let mut entries: Vec<build_types::NamesListLiteral> = Vec::new();
+
+    for line in fs::read_to_string(&ucd_path).unwrap().lines() {
+        if let Some((cp, _name)) = line.split_once('\t')
+            && !cp.is_empty()
+            && cp.chars().all(|ch| ch.is_ascii_hexdigit())
+        {
+            entries.push(build_types::NamesListLiteral {
+                codepoint: u32::from_str_radix(cp, 16).unwrap(),
+                ..Default::default()
+            });
+
+            continue;
+        }
+
+        let (Some(annotation), Some(entry)) = (line.strip_prefix('\t'), entries.last_mut()) else {
+            continue;
+        };
+
+        if let Some(alias) = annotation.strip_prefix("= ") {
+            entry.informal_aliases.push(alias.to_owned());
+        } else if let Some(xref) = annotation.strip_prefix("x ") {
+            // either `x (name - 1234)` or just `x 1234`
+            let cp = xref
+                .trim_end_matches(')')
+                .rsplit([' ', '-'])
+                .next()
+                .unwrap();
+
+            entry
+                .cross_references
+                .push(u32::from_str_radix(cp, 16).unwrap());
+        }
+    }
+
+    for entry in entries {
+        if entry.informal_aliases.is_empty() && entry.cross_references.is_empty() {
+            continue;
+        }
+
+        phf_source.entry(entry.codepoint, format!("&{entry:?}"));
+    }
+
+    writeln!(
+        &mut writer,
+        "pub static NAMES_LIST: phf::Map<u32, &NamesListStatic> = {};\n",
+        phf_source.build()
+    )
+    .unwrap();
+
+    println!("cargo::rerun-if-changed={}", ucd_path.to_str().unwrap());
+    println!("cargo::rerun-if-changed={}", out_path.to_str().unwrap());
+
+    drop(writer);
+    source_file.set_modified(codegen_file_time).unwrap();
+}
+
+fn generate_blocks(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    generate_ucd_fields_source(
+        ucd_dir,
+        "Blocks.txt",
+        &out_dir.join("blocks.rs"),
+        codegen_file_time,
+        |mut writer, parsed| {
+            let blocks = parsed.into_iter().map(|fields| {
+                let (start, end) = parse_codepoint_range(&fields[0]);
+                (start, end, format!("{:?}", fields[1]))
+            });
+
+            write_range_table(&mut writer, "BLOCKS", "&str", blocks);
+        },
+    )
+}
+
+fn generate_scripts(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    generate_ucd_table_source(
+        ucd_dir,
+        &out_dir.join("scripts.rs"),
+        codegen_file_time,
+        |mut writer, parsed: Vec<ucd_parse::Script>| {
+            let scripts = parsed.into_iter().map(|row| {
+                let (start, end) = codepoints_bounds(row.codepoints);
+                (start, end, format!("{:?}", row.script))
+            });
+
+            write_range_table(&mut writer, "SCRIPTS", "&str", scripts);
+        },
+    )
+}
+
+fn generate_script_extensions(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    generate_ucd_table_source(
+        ucd_dir,
+        &out_dir.join("script_extensions.rs"),
+        codegen_file_time,
+        |mut writer, parsed: Vec<ucd_parse::ScriptExtension>| {
+            let extensions = parsed.into_iter().map(|row| {
+                let (start, end) = codepoints_bounds(row.codepoints);
+                (start, end, format!("&{:?}", row.scripts.as_slice()))
+            });
+
+            write_range_table(&mut writer, "SCRIPT_EXTENSIONS", "&[&str]", extensions);
+        },
+    )
+}
+
+fn generate_property_value_aliases(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    generate_ucd_table_source(
+        ucd_dir,
+        &out_dir.join("property_value_aliases.rs"),
+        codegen_file_time,
+        |mut writer, parsed: Vec<ucd_parse::PropertyValueAlias>| {
+            writeln!(
+                &mut writer,
+                "pub static PROPERTY_VALUE_ALIASES: &[PropertyValueAliasStatic] = &["
+            )
+            .unwrap();
+
+            for row in parsed {
+                writeln!(
+                    &mut writer,
+                    "    PropertyValueAliasStatic {{ property: {:?}, abbreviation: {:?}, long: {:?}, aliases: &{:?} }},",
+                    row.property,
+                    row.abbreviation,
+                    row.long,
+                    row.aliases.as_slice()
+                )
+                .unwrap();
+            }
+
+            writeln!(&mut writer, "];\n").unwrap();
+        },
+    )
+}
+
+fn generate_ages(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    generate_ucd_table_source(
+        ucd_dir,
+        &out_dir.join("ages.rs"),
+        codegen_file_time,
+        |mut writer, parsed: Vec<ucd_parse::Age>| {
+            let ages = parsed.into_iter().map(|row| {
+                let (start, end) = codepoints_bounds(row.codepoints);
+                (start, end, format!("{:?}", row.age))
+            });
+
+            write_range_table(&mut writer, "AGES", "&str", ages);
+        },
+    )
+}
+
+fn generate_east_asian_widths(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    generate_ucd_table_source(
+        ucd_dir,
+        &out_dir.join("east_asian_widths.rs"),
+        codegen_file_time,
+        |mut writer, parsed: Vec<ucd_parse::EastAsianWidth>| {
+            let widths = parsed.into_iter().map(|row| {
+                let (start, end) = codepoints_bounds(row.codepoints);
+                (start, end, format!("{:?}", row.width))
+            });
+
+            write_range_table(&mut writer, "EAST_ASIAN_WIDTHS", "&str", widths);
+        },
+    )
+}
+
+/// The emoji properties which are compiled, with the names of their tables.
+const EMOJI_PROPERTIES: &[(&str, &str)] = &[
+    ("Emoji", "EMOJI"),
+    ("Emoji_Presentation", "EMOJI_PRESENTATION"),
+    ("Emoji_Modifier", "EMOJI_MODIFIER"),
+    ("Emoji_Modifier_Base", "EMOJI_MODIFIER_BASE"),
+    ("Emoji_Component", "EMOJI_COMPONENT"),
+    ("Extended_Pictographic", "EXTENDED_PICTOGRAPHIC"),
+];
+
+fn generate_emoji_properties(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    generate_ucd_table_source(
+        ucd_dir,
+        &out_dir.join("emoji_properties.rs"),
+        codegen_file_time,
+        |mut writer, parsed: Vec<ucd_parse::EmojiProperty>| {
+            for (property, table_name) in EMOJI_PROPERTIES {
+                let ranges = parsed
+                    .iter()
+                    .filter(|row| row.property == *property)
+                    .map(|row| {
+                        let (start, end) = codepoints_bounds(row.codepoints);
+                        (start, end, "()".to_owned())
+                    });
+
+                write_range_table(&mut writer, table_name, "()", ranges);
+            }
+        },
+    )
+}
+
+fn generate_emoji_variation_sequences(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    generate_ucd_fields_source(
+        ucd_dir,
+        "emoji/emoji-variation-sequences.txt",
+        &out_dir.join("emoji_variation_sequences.rs"),
+        codegen_file_time,
+        |mut writer, parsed| {
+            // every base has both a text and an emoji style sequence, so the
+            // bases are all that need to be kept
+            let bases = parsed
+                .into_iter()
+                .filter(|fields| fields[1] == "emoji style")
+                .map(|fields| {
+                    let base = parse_codepoint_sequence(&fields[0])[0];
+                    (base, base, "()".to_owned())
+                });
+
+            write_range_table(&mut writer, "EMOJI_VARIATION_BASES", "()", bases);
+        },
+    )
+}
+
+fn generate_grapheme_cluster_breaks(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    generate_ucd_table_source(
+        ucd_dir,
+        &out_dir.join("grapheme_cluster_breaks.rs"),
+        codegen_file_time,
+        |mut writer, parsed: Vec<ucd_parse::GraphemeClusterBreak>| {
+            let breaks = parsed.into_iter().map(|row| {
+                let (start, end) = codepoints_bounds(row.codepoints);
+                (
+                    start,
+                    end,
+                    format!("GraphemeClusterBreak::{}", enum_variant(&row.value)),
+                )
+            });
+
+            write_range_table(
+                &mut writer,
+                "GRAPHEME_CLUSTER_BREAKS",
+                "GraphemeClusterBreak",
+                breaks,
+            );
+        },
+    )
+}
+
+fn generate_indic_conjunct_breaks(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    // ucd-parse only keeps the property name of each row, but InCB is the one
+    // property in this file with a value
+    generate_ucd_fields_source(
+        ucd_dir,
+        "DerivedCoreProperties.txt",
+        &out_dir.join("indic_conjunct_breaks.rs"),
+        codegen_file_time,
+        |mut writer, parsed| {
+            let breaks = parsed
+                .into_iter()
+                .filter(|fields| fields[1] == "InCB")
+                .map(|fields| {
+                    let (start, end) = parse_codepoint_range(&fields[0]);
+                    (
+                        start,
+                        end,
+                        format!("IndicConjunctBreak::{}", enum_variant(&fields[2])),
+                    )
+                });
+
+            write_range_table(
+                &mut writer,
+                "INDIC_CONJUNCT_BREAKS",
+                "IndicConjunctBreak",
+                breaks,
+            );
+        },
+    )
+}
+
+fn generate_binary_properties(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    for (relative_path, out_name, table_name) in [
+        ("PropList.txt", "prop_list.rs", "PROP_LIST"),
+        (
+            "DerivedCoreProperties.txt",
+            "core_properties.rs",
+            "CORE_PROPERTIES",
+        ),
+    ] {
+        generate_ucd_fields_source(
+            ucd_dir,
+            relative_path,
+            &out_dir.join(out_name),
+            codegen_file_time,
+            |mut writer, parsed| {
+                // rows with a value belong to enumerated properties, like InCB
+                let parsed: Vec<_> = parsed
+                    .into_iter()
+                    .filter(|fields| fields.len() == 2)
+                    .collect();
+
+                let mut properties: Vec<&str> = Vec::new();
+
+                for fields in &parsed {
+                    if !properties.contains(&fields[1].as_str()) {
+                        properties.push(&fields[1]);
+                    }
+                }
+
+                writeln!(
+                    writer,
+                    "pub static {table_name}: &[(&str, RangeTable<()>)] = &["
+                )
+                .unwrap();
+
+                for property in properties {
+                    let ranges =
+                        parsed
+                            .iter()
+                            .filter(|fields| fields[1] == property)
+                            .map(|fields| {
+                                let (start, end) = parse_codepoint_range(&fields[0]);
+                                (start, end, "()".to_owned())
+                            });
+
+                    writeln!(
+                        writer,
+                        "({property:?}, {}),",
+                        range_table_literal(property, ranges)
+                    )
+                    .unwrap();
+                }
+
+                writeln!(writer, "];").unwrap();
+            },
+        );
+    }
+}
+
+fn generate_composition_exclusions(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    generate_ucd_fields_source(
+        ucd_dir,
+        "CompositionExclusions.txt",
+        &out_dir.join("composition_exclusions.rs"),
+        codegen_file_time,
+        |mut writer, parsed| {
+            let exclusions = parsed.into_iter().map(|fields| {
+                let (start, end) = parse_codepoint_range(&fields[0]);
+                (start, end, "()".to_owned())
+            });
+
+            write_range_table(&mut writer, "COMPOSITION_EXCLUSIONS", "()", exclusions);
+        },
+    )
+}
+
+const QUICK_CHECK_PROPERTIES: &[(&str, &str)] = &[
+    ("NFC_QC", "NFC_QUICK_CHECKS"),
+    ("NFD_QC", "NFD_QUICK_CHECKS"),
+    ("NFKC_QC", "NFKC_QUICK_CHECKS"),
+    ("NFKD_QC", "NFKD_QUICK_CHECKS"),
+];
+
+fn generate_quick_checks(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    // ucd-parse drops the values of the quick check properties
+    generate_ucd_fields_source(
+        ucd_dir,
+        "DerivedNormalizationProps.txt",
+        &out_dir.join("quick_checks.rs"),
+        codegen_file_time,
+        |mut writer, parsed| {
+            for (property, table_name) in QUICK_CHECK_PROPERTIES {
+                let ranges = parsed
+                    .iter()
+                    .filter(|fields| fields[1] == *property)
+                    .map(|fields| {
+                        let (start, end) = parse_codepoint_range(&fields[0]);
+                        let value = match fields[2].as_str() {
+                            "N" => "No",
+                            "M" => "Maybe",
+                            value => panic!("unexpected {property} value {value}"),
+                        };
+
+                        (start, end, format!("QuickCheck::{value}"))
+                    });
+
+                write_range_table(&mut writer, table_name, "QuickCheck", ranges);
+            }
+        },
+    )
+}
+
+fn generate_word_breaks(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    generate_ucd_table_source(
+        ucd_dir,
+        &out_dir.join("word_breaks.rs"),
+        codegen_file_time,
+        |mut writer, parsed: Vec<ucd_parse::WordBreak>| {
+            let breaks = parsed.into_iter().map(|row| {
+                let (start, end) = codepoints_bounds(row.codepoints);
+                (
+                    start,
+                    end,
+                    format!("WordBreak::{}", enum_variant(&row.value)),
+                )
+            });
+
+            write_range_table(&mut writer, "WORD_BREAKS", "WordBreak", breaks);
+        },
+    )
+}
+
+fn generate_sentence_breaks(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    generate_ucd_table_source(
+        ucd_dir,
+        &out_dir.join("sentence_breaks.rs"),
+        codegen_file_time,
+        |mut writer, parsed: Vec<ucd_parse::SentenceBreak>| {
+            let breaks = parsed.into_iter().map(|row| {
+                let (start, end) = codepoints_bounds(row.codepoints);
+                (
+                    start,
+                    end,
+                    format!("SentenceBreak::{}", enum_variant(&row.value)),
+                )
+            });
+
+            write_range_table(&mut writer, "SENTENCE_BREAKS", "SentenceBreak", breaks);
+        },
+    )
+}
+
+fn generate_line_breaks(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    // ucd-parse has no parser for LineBreak.txt, only for its test file
+    generate_ucd_fields_source(
+        ucd_dir,
+        "LineBreak.txt",
+        &out_dir.join("line_breaks.rs"),
+        codegen_file_time,
+        |mut writer, parsed| {
+            let breaks = parsed.into_iter().map(|fields| {
+                let (start, end) = parse_codepoint_range(&fields[0]);
+                (
+                    start,
+                    end,
+                    format!("LineBreak::{}", enum_variant(&fields[1])),
+                )
+            });
+
+            write_range_table(&mut writer, "LINE_BREAKS", "LineBreak", breaks);
+        },
+    )
+}
+
+fn generate_bidi_classes(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+    let relative_path = "extracted/DerivedBidiClass.txt";
+
+    generate_ucd_fields_source(
+        ucd_dir,
+        relative_path,
+        &out_dir.join("bidi_classes.rs"),
+        codegen_file_time,
+        |mut writer, parsed| {
+            let classes = parsed.into_iter().map(|fields| {
+                let (start, end) = parse_codepoint_range(&fields[0]);
+                (
+                    start,
+                    end,
+                    format!("BidiClass::{}", enum_variant(&fields[1])),
+                )
+            });
+
+            write_range_table(&mut writer, "BIDI_CLASSES", "BidiClass", classes);
+
+            // unassigned codepoints in blocks for right-to-left scripts
+            // default to R or AL, which is only given by the @missing lines
+            let source = fs::read_to_string(ucd_dir.join(relative_path)).unwrap();
+            let defaults = source
+                .lines()
+                .filter_map(|line| line.strip_prefix("# @missing:"))
+                .filter_map(|line| {
+                    let (range, value) = line.split_once(';')?;
+                    let (start, end) = parse_codepoint_range(range.trim());
+                    let value = match value.trim() {
+                        // the default for everything else
+                        "Left_To_Right" => return None,
+                        "Right_To_Left" => "R",
+                        "Arabic_Letter" => "Al",
+                        "European_Terminator" => "Et",
+                        value => panic!("unexpected Bidi_Class default {value}"),
+                    };
+
+                    Some((start, end, format!("BidiClass::{value}")))
+                });
+
+            write_range_table(&mut writer, "BIDI_CLASS_DEFAULTS", "BidiClass", defaults);
+        },
+    )
+}
+
+fn generate_bidi_mirroring(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    generate_ucd_fields_source(
+        ucd_dir,
+        "BidiMirroring.txt",
+        &out_dir.join("bidi_mirroring.rs"),
+        codegen_file_time,
+        |mut writer, parsed| {
+            let glyphs = parsed.into_iter().map(|fields| {
+                let (cp, _) = parse_codepoint_range(&fields[0]);
+                let (glyph, _) = parse_codepoint_range(&fields[1]);
+                (cp, cp, format!("{glyph:#X}"))
+            });
+
+            write_range_table(&mut writer, "BIDI_MIRRORING_GLYPHS", "u32", glyphs);
+        },
+    )
+}
+
+fn generate_bidi_brackets(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    generate_ucd_fields_source(
+        ucd_dir,
+        "BidiBrackets.txt",
+        &out_dir.join("bidi_brackets.rs"),
+        codegen_file_time,
+        |mut writer, parsed| {
+            let brackets = parsed.into_iter().map(|fields| {
+                let (cp, _) = parse_codepoint_range(&fields[0]);
+                let (pair, _) = parse_codepoint_range(&fields[1]);
+                let bracket_type = match fields[2].as_str() {
+                    "o" => "Open",
+                    "c" => "Close",
+                    value => panic!("unexpected Bidi_Paired_Bracket_Type {value}"),
+                };
+
+                (
+                    cp,
+                    cp,
+                    format!(
+                        "PairedBracket {{ pair: {pair:#X}, bracket_type: BracketType::{bracket_type} }}"
+                    ),
+                )
+            });
+
+            write_range_table(
+                &mut writer,
+                "BIDI_PAIRED_BRACKETS",
+                "PairedBracket",
+                brackets,
+            );
+        },
+    )
+}
+
+fn generate_special_casings(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    generate_ucd_source(
+        ucd_dir,
+        &out_dir.join("special_casings.rs"),
+        codegen_file_time,
+        |mut writer,
+         parsed: BTreeMap<Codepoint, Vec<ucd_parse::SpecialCaseMapping>>,
+         mut phf_source| {
+            for (codepoint, mappings) in parsed.into_iter() {
+                let mappings: Vec<_> = mappings.into_iter().map(SpecialCasingLiteral).collect();
+                phf_source.entry(codepoint.value(), format!("&{:?}", mappings.as_slice()));
+            }
+
+            writeln!(
+                &mut writer,
+                "pub static SPECIAL_CASINGS: phf::Map<u32, &[SpecialCasing]> = {};\n",
+                phf_source.build()
+            )
+            .unwrap();
+        },
+    )
+}
+
+fn generate_case_foldings(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    generate_ucd_source(
+        ucd_dir,
+        &out_dir.join("case_foldings.rs"),
+        codegen_file_time,
+        |mut writer, parsed: BTreeMap<Codepoint, Vec<ucd_parse::CaseFold>>, mut phf_source| {
+            for (codepoint, foldings) in parsed.into_iter() {
+                let foldings: Vec<_> = foldings.into_iter().map(CaseFoldingLiteral).collect();
+                phf_source.entry(codepoint.value(), format!("&{:?}", foldings.as_slice()));
+            }
+
+            writeln!(
+                &mut writer,
+                "pub static CASE_FOLDINGS: phf::Map<u32, &[CaseFolding]> = {};\n",
+                phf_source.build()
+            )
+            .unwrap();
+        },
+    )
+}
+
+fn generate_nfkc_casefolds(ucd_dir: &Path, out_dir: &Path) {
+    let codegen_file_time = get_codegen_file_time().unwrap();
+
+    generate_ucd_fields_source(
+        ucd_dir,
+        "DerivedNormalizationProps.txt",
+        &out_dir.join("nfkc_casefolds.rs"),
+        codegen_file_time,
+        |mut writer, parsed| {
+            for (property, table_name) in [
+                ("NFKC_CF", "NFKC_CASEFOLDS"),
+                ("NFKC_SCF", "NFKC_SIMPLE_CASEFOLDS"),
+            ] {
+                let mappings = parsed
+                    .iter()
+                    .filter(|fields| fields[1] == property)
+                    .map(|fields| {
+                        let (start, end) = parse_codepoint_range(&fields[0]);
+                        let mapping: Vec<_> = parse_codepoint_sequence(&fields[2])
+                            .into_iter()
+                            .map(|cp| format!("{cp:#X}"))
+                            .collect();
+
+                        (start, end, format!("&[{}]", mapping.join(", ")))
+                    });
+
+                write_range_table(&mut writer, table_name, "&[u32]", mappings);
+            }
+
+            let changes = parsed
+                .iter()
+                .filter(|fields| fields[1] == "Changes_When_NFKC_Casefolded")
+                .map(|fields| {
+                    let (start, end) = parse_codepoint_range(&fields[0]);
+                    (start, end, "()".to_owned())
+                });
+
+            write_range_table(&mut writer, "CHANGES_WHEN_NFKC_CASEFOLDED", "()", changes);
+        },
+    )
+}
@@ -0,0 +1,51 @@
+use ucd_parse::{CaseFold, CaseStatus, Codepoint, SpecialCaseMapping};
+
+fn codepoint_values(codepoints: &[Codepoint]) -> Vec<u32> {
+    codepoints.iter().map(|cp| cp.value()).collect()
+}
+
+pub struct SpecialCasingLiteral(pub SpecialCaseMapping);
+
+impl std::fmt::Debug for SpecialCasingLiteral {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("SpecialCasing")
+            .field(
+                "lowercase",
+                &format_args!("&{:?}", codepoint_values(&self.0.lowercase)),
+            )
+            .field(
+                "titlecase",
+                &format_args!("&{:?}", codepoint_values(&self.0.titlecase)),
+            )
+            .field(
+                "uppercase",
+                &format_args!("&{:?}", codepoint_values(&self.0.uppercase)),
+            )
+            .field(
+                "conditions",
+                &format_args!("&{:?}", self.0.conditions.as_slice()),
+            )
+            .finish()
+    }
+}
+
+pub struct CaseFoldingLiteral(pub CaseFold);
+
+impl std::fmt::Debug for CaseFoldingLiteral {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let status = match self.0.status {
+            CaseStatus::Common => "Common",
+            CaseStatus::Full => "Full",
+            CaseStatus::Simple => "Simple",
+            CaseStatus::Special => "Turkic",
+        };
+
+        fmt.debug_struct("CaseFolding")
+            .field("status", &format_args!("CaseFoldingStatus::{status}"))
+            .field(
+                "mapping",
+                &format_args!("&{:?}", codepoint_values(&self.0.mapping)),
+            )
+            .finish()
+    }
+}
@@ -1,4 +1,7 @@
+pub use names_list::NamesListLiteral;
 pub use unicode_data::UnicodeDataLiteral;
 
+pub mod casing;
 pub mod name_aliases;
+pub mod names_list;
 pub mod unicode_data;
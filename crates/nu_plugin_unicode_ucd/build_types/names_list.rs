@@ -0,0 +1,22 @@
+#[derive(Default)]
+pub struct NamesListLiteral {
+    pub codepoint: u32,
+    pub informal_aliases: Vec<String>,
+    pub cross_references: Vec<u32>,
+}
+
+impl std::fmt::Debug for NamesListLiteral {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("NamesListStatic")
+            .field("codepoint", &self.codepoint)
+            .field(
+                "informal_aliases",
+                &format_args!("&{:?}", self.informal_aliases.as_slice()),
+            )
+            .field(
+                "cross_references",
+                &format_args!("&{:?}", self.cross_references.as_slice()),
+            )
+            .finish()
+    }
+}
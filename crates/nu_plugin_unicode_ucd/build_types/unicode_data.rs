@@ -93,7 +93,7 @@ impl std::fmt::Debug for UnicodeDataLiteral {
 
         fmt.debug_struct("UnicodeDataStatic")
             .field("codepoint", &self.0.codepoint.value())
-            .field("name", &self.0.name)
+            .field("name", &format_args!("Cow::Borrowed({:?})", self.0.name))
             .field("general_category", &self.0.general_category)
             .field(
                 "canonical_combining_class",
@@ -0,0 +1,25 @@
+use crate::types::ranges::RangeTable;
+
+include!(concat!(env!("OUT_DIR"), "/ages.rs"));
+
+/// The value of the Age property for unassigned codepoints.
+pub const UNASSIGNED_AGE: &str = "NA";
+
+/// Returns the version of Unicode in which the codepoint was assigned, e.g.
+/// `"6.0"`.
+pub fn age_of(codepoint: u32) -> &'static str {
+    AGES.get(codepoint).copied().unwrap_or(UNASSIGNED_AGE)
+}
+
+/// Parses a Unicode version like `"9.0"`, `"9"` or `"15.1.0"` into its major
+/// and minor components, which is all the Age property distinguishes.
+pub fn parse_version(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.trim().split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().map_or(Some(0), |minor| minor.parse().ok())?;
+
+    match parts.next() {
+        None => Some((major, minor)),
+        Some(patch) => patch.parse::<u32>().ok().map(|_| (major, minor)),
+    }
+}
@@ -0,0 +1,31 @@
+use crate::types::{
+    bidi::{BidiClass, BracketType, PairedBracket},
+    ranges::RangeTable,
+};
+
+include!(concat!(env!("OUT_DIR"), "/bidi_classes.rs"));
+include!(concat!(env!("OUT_DIR"), "/bidi_mirroring.rs"));
+include!(concat!(env!("OUT_DIR"), "/bidi_brackets.rs"));
+
+/// Returns the Bidi_Class of the codepoint. Unassigned codepoints are L,
+/// unless they are in a block which defaults to something else, such as R
+/// for the Hebrew block.
+pub fn bidi_class_of(codepoint: u32) -> BidiClass {
+    BIDI_CLASSES
+        .get(codepoint)
+        .or_else(|| BIDI_CLASS_DEFAULTS.get(codepoint))
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Returns the Bidi_Mirroring_Glyph of the codepoint, i.e. the character
+/// which looks like its mirror image, if there is one.
+pub fn bidi_mirroring_glyph_of(codepoint: u32) -> Option<u32> {
+    BIDI_MIRRORING_GLYPHS.get(codepoint).copied()
+}
+
+/// Returns the Bidi_Paired_Bracket of the codepoint and its type, if it is
+/// a bracket.
+pub fn bidi_paired_bracket_of(codepoint: u32) -> Option<&'static PairedBracket> {
+    BIDI_PAIRED_BRACKETS.get(codepoint)
+}
@@ -0,0 +1,23 @@
+use crate::{
+    codegen::{emoji_properties::EMOJI_PROPERTIES, normalization::CHANGES_WHEN_NFKC_CASEFOLDED},
+    types::ranges::RangeTable,
+};
+
+include!(concat!(env!("OUT_DIR"), "/prop_list.rs"));
+include!(concat!(env!("OUT_DIR"), "/core_properties.rs"));
+
+/// Iterates over every binary property from `PropList.txt`,
+/// `DerivedCoreProperties.txt` and `emoji-data.txt`, and
+/// Changes_When_NFKC_Casefolded from `DerivedNormalizationProps.txt`, with the
+/// set of codepoints for which it is true.
+pub fn binary_properties() -> impl Iterator<Item = (&'static str, &'static RangeTable<()>)> {
+    PROP_LIST
+        .iter()
+        .chain(CORE_PROPERTIES)
+        .map(|(name, table)| (*name, table))
+        .chain(EMOJI_PROPERTIES.iter().copied())
+        .chain([(
+            "Changes_When_NFKC_Casefolded",
+            &CHANGES_WHEN_NFKC_CASEFOLDED,
+        )])
+}
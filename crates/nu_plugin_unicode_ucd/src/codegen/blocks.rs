@@ -0,0 +1,6 @@
+use crate::types::ranges::RangeTable;
+
+include!(concat!(env!("OUT_DIR"), "/blocks.rs"));
+
+/// The value of the Block property for codepoints outside of any block.
+pub const NO_BLOCK: &str = "No_Block";
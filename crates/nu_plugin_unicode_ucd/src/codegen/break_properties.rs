@@ -0,0 +1,38 @@
+use crate::types::{
+    break_properties::{
+        GraphemeClusterBreak, IndicConjunctBreak, LineBreak, SentenceBreak, WordBreak,
+    },
+    ranges::RangeTable,
+};
+
+include!(concat!(env!("OUT_DIR"), "/grapheme_cluster_breaks.rs"));
+include!(concat!(env!("OUT_DIR"), "/indic_conjunct_breaks.rs"));
+include!(concat!(env!("OUT_DIR"), "/word_breaks.rs"));
+include!(concat!(env!("OUT_DIR"), "/sentence_breaks.rs"));
+include!(concat!(env!("OUT_DIR"), "/line_breaks.rs"));
+
+pub fn grapheme_cluster_break_of(codepoint: u32) -> GraphemeClusterBreak {
+    GRAPHEME_CLUSTER_BREAKS
+        .get(codepoint)
+        .copied()
+        .unwrap_or_default()
+}
+
+pub fn indic_conjunct_break_of(codepoint: u32) -> IndicConjunctBreak {
+    INDIC_CONJUNCT_BREAKS
+        .get(codepoint)
+        .copied()
+        .unwrap_or_default()
+}
+
+pub fn word_break_of(codepoint: u32) -> WordBreak {
+    WORD_BREAKS.get(codepoint).copied().unwrap_or_default()
+}
+
+pub fn sentence_break_of(codepoint: u32) -> SentenceBreak {
+    SENTENCE_BREAKS.get(codepoint).copied().unwrap_or_default()
+}
+
+pub fn line_break_of(codepoint: u32) -> LineBreak {
+    LINE_BREAKS.get(codepoint).copied().unwrap_or_default()
+}
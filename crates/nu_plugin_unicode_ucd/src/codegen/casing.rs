@@ -0,0 +1,43 @@
+use crate::{
+    codegen::unicode_data::UNICODE_DATA,
+    types::casing::{CaseFolding, CaseFoldingStatus, SpecialCasing},
+};
+
+include!(concat!(env!("OUT_DIR"), "/special_casings.rs"));
+include!(concat!(env!("OUT_DIR"), "/case_foldings.rs"));
+
+/// Returns the rows of `SpecialCasing.txt` for the codepoint, which is
+/// empty if its full case mappings are the same as its simple ones.
+pub fn special_casings_of(codepoint: u32) -> &'static [SpecialCasing] {
+    SPECIAL_CASINGS.get(&codepoint).copied().unwrap_or_default()
+}
+
+/// Returns the rows of `CaseFolding.txt` for the codepoint, which is empty
+/// if it folds to itself.
+pub fn case_foldings_of(codepoint: u32) -> &'static [CaseFolding] {
+    CASE_FOLDINGS.get(&codepoint).copied().unwrap_or_default()
+}
+
+/// Returns the Simple_Lowercase_Mapping of the codepoint, if it has one.
+pub fn simple_lowercase_of(codepoint: u32) -> Option<u32> {
+    UNICODE_DATA
+        .get(&codepoint)
+        .and_then(|data| data.simple_lowercase_mapping)
+}
+
+/// Returns the Simple_Uppercase_Mapping of the codepoint, if it has one.
+pub fn simple_uppercase_of(codepoint: u32) -> Option<u32> {
+    UNICODE_DATA
+        .get(&codepoint)
+        .and_then(|data| data.simple_uppercase_mapping)
+}
+
+/// Returns the Simple_Titlecase_Mapping of the codepoint, if it has one.
+/// Where `UnicodeData.txt` leaves it empty, it is the same as the
+/// Simple_Uppercase_Mapping.
+pub fn simple_titlecase_of(codepoint: u32) -> Option<u32> {
+    UNICODE_DATA.get(&codepoint).and_then(|data| {
+        data.simple_titlecase_mapping
+            .or(data.simple_uppercase_mapping)
+    })
+}
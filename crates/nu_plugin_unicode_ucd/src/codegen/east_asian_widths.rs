@@ -0,0 +1,9 @@
+use crate::types::ranges::RangeTable;
+
+include!(concat!(env!("OUT_DIR"), "/east_asian_widths.rs"));
+
+/// Returns the abbreviated East_Asian_Width of the codepoint, e.g. `W`.
+/// Codepoints not listed in `EastAsianWidth.txt` are `N` (neutral).
+pub fn east_asian_width_of(codepoint: u32) -> &'static str {
+    EAST_ASIAN_WIDTHS.get(codepoint).copied().unwrap_or("N")
+}
@@ -0,0 +1,14 @@
+use crate::types::ranges::RangeTable;
+
+include!(concat!(env!("OUT_DIR"), "/emoji_properties.rs"));
+include!(concat!(env!("OUT_DIR"), "/emoji_variation_sequences.rs"));
+
+/// The emoji properties from `emoji-data.txt`, by name.
+pub static EMOJI_PROPERTIES: &[(&str, &RangeTable<()>)] = &[
+    ("Emoji", &EMOJI),
+    ("Emoji_Presentation", &EMOJI_PRESENTATION),
+    ("Emoji_Modifier", &EMOJI_MODIFIER),
+    ("Emoji_Modifier_Base", &EMOJI_MODIFIER_BASE),
+    ("Emoji_Component", &EMOJI_COMPONENT),
+    ("Extended_Pictographic", &EXTENDED_PICTOGRAPHIC),
+];
@@ -1,2 +1,16 @@
+pub mod ages;
+pub mod bidi;
+pub mod binary_properties;
+pub mod blocks;
+pub mod break_properties;
+pub mod casing;
+pub mod east_asian_widths;
+pub mod emoji_properties;
 pub mod name_aliases;
+pub mod named_sequences;
+pub mod names;
+pub mod names_list;
+pub mod normalization;
+pub mod property_value_aliases;
+pub mod scripts;
 pub mod unicode_data;
@@ -0,0 +1,36 @@
+use std::borrow::Cow;
+
+use crate::{codegen::unicode_data::UNICODE_DATA, hangul, types::ranges::RangeTable};
+
+include!(concat!(env!("OUT_DIR"), "/jamo_short_names.rs"));
+include!(concat!(env!("OUT_DIR"), "/name_patterns.rs"));
+
+/// Returns the value of the Name property of the given codepoint.
+///
+/// This covers both the names stored in `UnicodeData.txt` and the names
+/// which are derived algorithmically, i.e. rule NR1 for Hangul syllables and
+/// rule NR2 for the ideographs and other characters whose name is a prefix
+/// followed by the codepoint in hex. Codepoints that have no name, such as
+/// controls, surrogates, private use and unassigned codepoints, return
+/// `None`.
+pub fn name_of(codepoint: u32) -> Option<Cow<'static, str>> {
+    if let Some((l, v, t)) = hangul::decompose_syllable(codepoint) {
+        let jamo = |cp| JAMO_SHORT_NAMES.get(&cp).copied().unwrap_or_default();
+
+        return Some(Cow::Owned(format!(
+            "HANGUL SYLLABLE {}{}{}",
+            jamo(l),
+            jamo(v),
+            t.map(jamo).unwrap_or_default()
+        )));
+    }
+
+    if let Some(prefix) = NAME_PATTERNS.get(codepoint) {
+        return Some(Cow::Owned(format!("{prefix}{codepoint:04X}")));
+    }
+
+    UNICODE_DATA
+        .get(&codepoint)
+        .map(|data| data.name.clone())
+        .filter(|name| !name.starts_with('<'))
+}
@@ -0,0 +1,3 @@
+use crate::types::names_list::NamesListStatic;
+
+include!(concat!(env!("OUT_DIR"), "/names_list.rs"));
@@ -0,0 +1,18 @@
+use crate::types::{normalization::QuickCheck, ranges::RangeTable};
+
+include!(concat!(env!("OUT_DIR"), "/composition_exclusions.rs"));
+include!(concat!(env!("OUT_DIR"), "/quick_checks.rs"));
+include!(concat!(env!("OUT_DIR"), "/nfkc_casefolds.rs"));
+
+/// Returns the NFKC_Casefold mapping of the codepoint, or its NFKC_Simple_Casefold
+/// mapping if `simple` is set, unless it maps to itself. Default ignorable
+/// codepoints map to nothing.
+pub fn nfkc_casefold_of(codepoint: u32, simple: bool) -> Option<&'static [u32]> {
+    let table = if simple {
+        &NFKC_SIMPLE_CASEFOLDS
+    } else {
+        &NFKC_CASEFOLDS
+    };
+
+    table.get(codepoint).copied()
+}
@@ -0,0 +1,18 @@
+use crate::types::property_value_aliases::PropertyValueAliasStatic;
+
+include!(concat!(env!("OUT_DIR"), "/property_value_aliases.rs"));
+
+/// Finds the aliases of a value of the given property, by any of its names.
+///
+/// `property` is the abbreviated property name, e.g. `sc` for Script.
+pub fn property_value_alias(
+    property: &str,
+    value: &str,
+) -> Option<&'static PropertyValueAliasStatic> {
+    PROPERTY_VALUE_ALIASES.iter().find(|alias| {
+        alias.property == property
+            && (alias.abbreviation == value
+                || alias.long == value
+                || alias.aliases.contains(&value))
+    })
+}
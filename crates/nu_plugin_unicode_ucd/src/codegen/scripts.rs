@@ -0,0 +1,31 @@
+use crate::{codegen::property_value_aliases::property_value_alias, types::ranges::RangeTable};
+
+include!(concat!(env!("OUT_DIR"), "/scripts.rs"));
+include!(concat!(env!("OUT_DIR"), "/script_extensions.rs"));
+
+/// The value of the Script property for codepoints not listed in
+/// `Scripts.txt`.
+pub const UNKNOWN_SCRIPT: &str = "Unknown";
+
+/// Returns the long name of the codepoint's script.
+pub fn script_of(codepoint: u32) -> &'static str {
+    SCRIPTS.get(codepoint).copied().unwrap_or(UNKNOWN_SCRIPT)
+}
+
+/// Returns the abbreviated name of the codepoint's script, e.g. `Latn`.
+pub fn script_abbreviation_of(codepoint: u32) -> &'static str {
+    property_value_alias("sc", script_of(codepoint)).map_or("Zzzz", |alias| alias.abbreviation)
+}
+
+/// Returns the abbreviated names of the scripts in the codepoint's
+/// Script_Extensions property. Codepoints not listed in
+/// `ScriptExtensions.txt` have just their own script.
+pub fn script_extensions_of(codepoint: u32) -> &'static [&'static str] {
+    if let Some(scripts) = SCRIPT_EXTENSIONS.get(codepoint) {
+        return scripts;
+    }
+
+    property_value_alias("sc", script_of(codepoint))
+        .map(|alias| std::slice::from_ref(&alias.abbreviation))
+        .unwrap_or_default()
+}
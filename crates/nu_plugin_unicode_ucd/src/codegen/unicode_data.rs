@@ -1,6 +1,66 @@
-use crate::types::unicode_data::{
-    UnicodeDataDecompositionStatic, UnicodeDataDecompositionTagStatic, UnicodeDataNumericStatic,
-    UnicodeDataStatic,
+use std::borrow::Cow;
+
+use crate::{
+    codegen::names::name_of,
+    types::{
+        ranges::RangeTable,
+        unicode_data::{
+            UnicodeDataDecompositionStatic, UnicodeDataDecompositionTagStatic,
+            UnicodeDataNumericStatic, UnicodeDataStatic,
+        },
+    },
 };
 
 include!(concat!(env!("OUT_DIR"), "/unicode_data.rs"));
+
+/// Looks up the `UnicodeData.txt` row for the given codepoint.
+///
+/// Codepoints that are only listed as part of a `<..., First>`/`<..., Last>`
+/// range get a copy of the range's properties, with the codepoint filled in
+/// and the name derived as per [`name_of`].
+pub fn unicode_data(codepoint: u32) -> Option<UnicodeDataStatic> {
+    if let Some(data) = UNICODE_DATA.get(&codepoint) {
+        return Some((*data).clone());
+    }
+
+    let range_data = UNICODE_DATA_RANGES.get(codepoint)?;
+
+    Some(UnicodeDataStatic {
+        codepoint,
+        name: name_of(codepoint).unwrap_or_default(),
+        ..(*range_data).clone()
+    })
+}
+
+/// Returns true if the codepoint is assigned, i.e. its general category is
+/// anything other than `Cn`.
+pub fn is_assigned(codepoint: u32) -> bool {
+    UNICODE_DATA.contains_key(&codepoint) || UNICODE_DATA_RANGES.contains(codepoint)
+}
+
+/// Returns the abbreviated general category of the codepoint, e.g. `Lu`.
+/// Unassigned codepoints are `Cn`.
+pub fn general_category_of(codepoint: u32) -> &'static str {
+    UNICODE_DATA
+        .get(&codepoint)
+        .or_else(|| UNICODE_DATA_RANGES.get(codepoint))
+        .map_or("Cn", |data| data.general_category)
+}
+
+/// Returns the canonical combining class of the codepoint, which is 0 for
+/// unassigned codepoints.
+pub fn canonical_combining_class_of(codepoint: u32) -> u8 {
+    UNICODE_DATA
+        .get(&codepoint)
+        .or_else(|| UNICODE_DATA_RANGES.get(codepoint))
+        .map_or(0, |data| data.canonical_combining_class)
+}
+
+/// Returns the decomposition mapping of the codepoint from
+/// `UnicodeData.txt`, if it has one. Hangul syllables are decomposed
+/// algorithmically, so they have none here.
+pub fn decomposition_of(codepoint: u32) -> Option<&'static UnicodeDataDecompositionStatic> {
+    UNICODE_DATA
+        .get(&codepoint)
+        .and_then(|data| data.decomposition.as_ref())
+}
@@ -0,0 +1,34 @@
+//! Constants and helpers for the algorithmic Hangul syllables, as described
+//! in [section 3.12](https://www.unicode.org/versions/latest/core-spec/chapter-3/#G24646)
+//! of the Unicode Standard.
+
+pub const S_BASE: u32 = 0xAC00;
+pub const L_BASE: u32 = 0x1100;
+pub const V_BASE: u32 = 0x1161;
+pub const T_BASE: u32 = 0x11A7;
+
+pub const L_COUNT: u32 = 19;
+pub const V_COUNT: u32 = 21;
+pub const T_COUNT: u32 = 28;
+pub const N_COUNT: u32 = V_COUNT * T_COUNT;
+pub const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+/// Returns true if the codepoint is a precomposed Hangul syllable.
+pub fn is_syllable(codepoint: u32) -> bool {
+    (S_BASE..S_BASE + S_COUNT).contains(&codepoint)
+}
+
+/// Splits a precomposed Hangul syllable into its leading consonant, vowel
+/// and, if present, trailing consonant jamo.
+pub fn decompose_syllable(codepoint: u32) -> Option<(u32, u32, Option<u32>)> {
+    if !is_syllable(codepoint) {
+        return None;
+    }
+
+    let s_index = codepoint - S_BASE;
+    let l = L_BASE + s_index / N_COUNT;
+    let v = V_BASE + (s_index % N_COUNT) / T_COUNT;
+    let t_index = s_index % T_COUNT;
+
+    Some((l, v, (t_index != 0).then_some(T_BASE + t_index)))
+}
@@ -0,0 +1,58 @@
+//! The properties used by the bidirectional algorithm of
+//! [UAX #9](https://www.unicode.org/reports/tr9/).
+
+/// The values of the Bidi_Class property, from
+/// `extracted/DerivedBidiClass.txt`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum BidiClass {
+    #[default]
+    L,
+    R,
+    Al,
+    En,
+    Es,
+    Et,
+    An,
+    Cs,
+    Nsm,
+    Bn,
+    B,
+    S,
+    Ws,
+    On,
+    Lre,
+    Lro,
+    Rle,
+    Rlo,
+    Pdf,
+    Lri,
+    Rli,
+    Fsi,
+    Pdi,
+}
+
+/// The values of the Bidi_Paired_Bracket_Type property, from
+/// `BidiBrackets.txt`, other than None.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BracketType {
+    Open,
+    Close,
+}
+
+impl BracketType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BracketType::Open => "open",
+            BracketType::Close => "close",
+        }
+    }
+}
+
+/// A row of `BidiBrackets.txt`: the Bidi_Paired_Bracket of a codepoint,
+/// along with its Bidi_Paired_Bracket_Type.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct PairedBracket {
+    /// The codepoint of the bracket which this one pairs with.
+    pub pair: u32,
+    pub bracket_type: BracketType,
+}
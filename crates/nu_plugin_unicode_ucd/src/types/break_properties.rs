@@ -0,0 +1,138 @@
+//! The properties used by the segmentation algorithms of
+//! [UAX #29](https://www.unicode.org/reports/tr29/).
+
+/// The values of the Grapheme_Cluster_Break property, from
+/// `auxiliary/GraphemeBreakProperty.txt`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum GraphemeClusterBreak {
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    #[default]
+    Other,
+}
+
+/// The values of the Indic_Conjunct_Break property, from
+/// `DerivedCoreProperties.txt`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum IndicConjunctBreak {
+    Consonant,
+    Extend,
+    Linker,
+    #[default]
+    None,
+}
+
+/// The values of the Word_Break property, from
+/// `auxiliary/WordBreakProperty.txt`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum WordBreak {
+    Cr,
+    Lf,
+    Newline,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Format,
+    Katakana,
+    HebrewLetter,
+    ALetter,
+    SingleQuote,
+    DoubleQuote,
+    MidNumLet,
+    MidLetter,
+    MidNum,
+    Numeric,
+    ExtendNumLet,
+    WSegSpace,
+    #[default]
+    Other,
+}
+
+/// The values of the Sentence_Break property, from
+/// `auxiliary/SentenceBreakProperty.txt`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum SentenceBreak {
+    Cr,
+    Lf,
+    Extend,
+    Sep,
+    Format,
+    Sp,
+    Lower,
+    Upper,
+    OLetter,
+    Numeric,
+    ATerm,
+    SContinue,
+    STerm,
+    Close,
+    #[default]
+    Other,
+}
+
+/// The values of the Line_Break property, from `LineBreak.txt`, as used by
+/// [UAX #14](https://www.unicode.org/reports/tr14/).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum LineBreak {
+    Bk,
+    Cr,
+    Lf,
+    Cm,
+    Nl,
+    Sg,
+    Wj,
+    Zw,
+    Gl,
+    Sp,
+    Zwj,
+    B2,
+    Ba,
+    Bb,
+    Hy,
+    Cb,
+    Cl,
+    Cp,
+    Ex,
+    In,
+    Ns,
+    Op,
+    Qu,
+    Is,
+    Nu,
+    Po,
+    Pr,
+    Sy,
+    Ai,
+    Ak,
+    Al,
+    Ap,
+    As,
+    Cj,
+    Eb,
+    Em,
+    H2,
+    H3,
+    Hh,
+    Hl,
+    Id,
+    Jl,
+    Jt,
+    Jv,
+    Ri,
+    Sa,
+    Vf,
+    Vi,
+    #[default]
+    Xx,
+}
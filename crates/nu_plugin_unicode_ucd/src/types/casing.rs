@@ -0,0 +1,40 @@
+//! The case mappings and case foldings from `SpecialCasing.txt` and
+//! `CaseFolding.txt`.
+
+/// A row of `SpecialCasing.txt`: the full case mappings of a codepoint,
+/// which only apply when all of the conditions hold.
+///
+/// Note that there are multiple rows for some codepoints, with different
+/// conditions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SpecialCasing {
+    pub lowercase: &'static [u32],
+    pub titlecase: &'static [u32],
+    pub uppercase: &'static [u32],
+    /// The language IDs, such as `tr`, and casing contexts, such as
+    /// `Final_Sigma`, which must all hold for the mappings to apply.
+    pub conditions: &'static [&'static str],
+}
+
+/// The status of a row of `CaseFolding.txt`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CaseFoldingStatus {
+    /// Used by both the simple and the full case folding.
+    Common,
+    /// Used by the full case folding, where the mapping is to several
+    /// codepoints.
+    Full,
+    /// Used by the simple case folding, where the full case folding maps
+    /// the codepoint to several others.
+    Simple,
+    /// Used instead of the other mappings for the dotted and dotless I in
+    /// Turkic languages. This is status `T` in the file.
+    Turkic,
+}
+
+/// A row of `CaseFolding.txt`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CaseFolding {
+    pub status: CaseFoldingStatus,
+    pub mapping: &'static [u32],
+}
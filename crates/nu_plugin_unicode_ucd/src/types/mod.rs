@@ -1,5 +1,12 @@
 //! These types are copied from the ucd-parse crate, but changed to have static
 //! types.
 
+pub mod bidi;
+pub mod break_properties;
+pub mod casing;
 pub mod name_aliases;
+pub mod names_list;
+pub mod normalization;
+pub mod property_value_aliases;
+pub mod ranges;
 pub mod unicode_data;
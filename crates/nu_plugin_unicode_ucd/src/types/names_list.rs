@@ -0,0 +1,14 @@
+/// The machine-readable annotations of a single entry in the `NamesList.txt`
+/// file.
+///
+/// Note that this file is meant for human readers, and the Unicode
+/// Consortium explicitly does not guarantee that its format is stable.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct NamesListStatic {
+    /// The codepoint this entry is for.
+    pub codepoint: u32,
+    /// The informal aliases of this codepoint, i.e. the lines marked with `=`.
+    pub informal_aliases: &'static [&'static str],
+    /// The codepoints this entry refers to, i.e. the lines marked with `x`.
+    pub cross_references: &'static [u32],
+}
@@ -0,0 +1,23 @@
+//! The properties used by the normalization algorithms of
+//! [UAX #15](https://www.unicode.org/reports/tr15/).
+
+/// The values of the NFC_QC, NFD_QC, NFKC_QC and NFKD_QC properties, from
+/// `DerivedNormalizationProps.txt`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum QuickCheck {
+    #[default]
+    Yes,
+    No,
+    Maybe,
+}
+
+impl QuickCheck {
+    /// The abbreviated name of the value, as used in the UCD.
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            QuickCheck::Yes => "Y",
+            QuickCheck::No => "N",
+            QuickCheck::Maybe => "M",
+        }
+    }
+}
@@ -0,0 +1,12 @@
+/// A single row in the `PropertyValueAliases.txt` file.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PropertyValueAliasStatic {
+    /// The abbreviated name of the property.
+    pub property: &'static str,
+    /// The abbreviated name of the value.
+    pub abbreviation: &'static str,
+    /// The long name of the value.
+    pub long: &'static str,
+    /// Any other aliases of the value.
+    pub aliases: &'static [&'static str],
+}
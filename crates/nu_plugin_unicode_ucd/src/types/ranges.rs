@@ -0,0 +1,42 @@
+/// A table of inclusive codepoint ranges, each associated with a value.
+///
+/// The ranges must be sorted and must not overlap, which the build script
+/// guarantees for every generated table. Lookups are a binary search.
+#[derive(Debug)]
+pub struct RangeTable<V: 'static>(pub &'static [(u32, u32, V)]);
+
+impl<V> RangeTable<V> {
+    /// Returns the value of the range containing `codepoint`, if any.
+    pub fn get(&self, codepoint: u32) -> Option<&'static V> {
+        self.get_range(codepoint).map(|(_, _, val)| val)
+    }
+
+    /// Returns the whole `(start, end, value)` entry of the range containing
+    /// `codepoint`, if any.
+    pub fn get_range(&self, codepoint: u32) -> Option<&'static (u32, u32, V)> {
+        let ranges = self.0;
+
+        ranges
+            .binary_search_by(|(start, end, _)| {
+                if *end < codepoint {
+                    std::cmp::Ordering::Less
+                } else if *start > codepoint {
+                    std::cmp::Ordering::Greater
+                } else {
+                    std::cmp::Ordering::Equal
+                }
+            })
+            .ok()
+            .map(|idx| &ranges[idx])
+    }
+
+    /// Returns true if `codepoint` falls within any range of the table.
+    pub fn contains(&self, codepoint: u32) -> bool {
+        self.get_range(codepoint).is_some()
+    }
+
+    /// Iterates over all `(start, end, value)` entries in codepoint order.
+    pub fn iter(&self) -> impl Iterator<Item = &'static (u32, u32, V)> + use<V> {
+        self.0.iter()
+    }
+}
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use nu_protocol::{IntoValue, Span, Value, record};
 use ucd_parse::{UnicodeDataDecompositionTag, UnicodeDataNumeric};
 
@@ -79,7 +81,10 @@ pub struct UnicodeDataStatic {
     /// The codepoint corresponding to this row.
     pub codepoint: u32,
     /// The name of this codepoint.
-    pub name: &'static str,
+    ///
+    /// Codepoints that are part of a range in `UnicodeData.txt` have their
+    /// name derived when they are looked up, so this is owned in that case.
+    pub name: Cow<'static, str>,
     /// The "general category" of this codepoint.
     pub general_category: &'static str,
     /// The class of this codepoint used in the Canonical Ordering Algorithm.
@@ -0,0 +1,726 @@
+use std::ops::Range;
+
+use nu_plugin_unicode_ucd::{
+    codegen::{
+        bidi::{bidi_class_of, bidi_mirroring_glyph_of, bidi_paired_bracket_of},
+        unicode_data::decomposition_of,
+    },
+    types::bidi::{BidiClass as Bc, BracketType},
+};
+
+/// The deepest embedding level, as per BD2.
+const MAX_DEPTH: u8 = 125;
+
+/// The maximum number of open brackets tracked by BD16.
+const MAX_BRACKET_PAIRS: usize = 63;
+
+/// The direction of a paragraph, which is either given explicitly, or
+/// detected from its first strong character as per P2 and P3.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Direction {
+    Ltr,
+    Rtl,
+    Auto,
+}
+
+impl Direction {
+    pub const NAMES: &[&str] = &["ltr", "rtl", "auto"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "ltr" => Some(Direction::Ltr),
+            "rtl" => Some(Direction::Rtl),
+            "auto" => Some(Direction::Auto),
+            _ => None,
+        }
+    }
+}
+
+/// A paragraph of text, with the embedding levels resolved by the
+/// [UAX #9](https://www.unicode.org/reports/tr9/) algorithm.
+#[derive(Clone, Debug)]
+pub struct Paragraph {
+    /// The byte range of the paragraph in the text, without the paragraph
+    /// separator which ends it.
+    pub range: Range<usize>,
+    /// The paragraph embedding level: 0 for left-to-right and 1 for
+    /// right-to-left.
+    pub level: u8,
+    /// The resolved embedding level of each character. Characters removed by
+    /// X9, such as embedding controls, get the level of the character before
+    /// them.
+    pub levels: Vec<u8>,
+    chars: Vec<char>,
+    /// Whether each character was removed by X9.
+    removed: Vec<bool>,
+}
+
+impl Paragraph {
+    /// Puts the paragraph into visual order, as a single line, as per L1 to
+    /// L4. Characters removed by X9 are left out, and characters at
+    /// right-to-left levels are replaced by their mirror image where there
+    /// is one.
+    pub fn visual(&self) -> String {
+        let mut indices: Vec<usize> = (0..self.chars.len())
+            .filter(|i| !self.removed[*i])
+            .collect();
+
+        // L2: from the highest level down to the lowest odd level, reverse
+        // every run of characters at that level or higher
+        let max_level = indices.iter().map(|i| self.levels[*i]).max().unwrap_or(0);
+        let min_odd_level = indices
+            .iter()
+            .map(|i| self.levels[*i])
+            .filter(|level| !level.is_multiple_of(2))
+            .min()
+            .unwrap_or(max_level + 1);
+
+        for level in (min_odd_level..=max_level).rev() {
+            let mut start = 0;
+
+            while start < indices.len() {
+                if self.levels[indices[start]] < level {
+                    start += 1;
+                    continue;
+                }
+
+                let len = indices[start..]
+                    .iter()
+                    .position(|i| self.levels[*i] < level)
+                    .unwrap_or(indices.len() - start);
+
+                indices[start..start + len].reverse();
+                start += len;
+            }
+        }
+
+        // L4
+        indices
+            .into_iter()
+            .map(|i| {
+                let ch = self.chars[i];
+
+                if !self.levels[i].is_multiple_of(2) {
+                    bidi_mirroring_glyph_of(ch as u32)
+                        .and_then(char::from_u32)
+                        .unwrap_or(ch)
+                } else {
+                    ch
+                }
+            })
+            .collect()
+    }
+}
+
+/// Splits the text into paragraphs as per P1, and resolves the embedding
+/// levels of each one. The paragraph separators themselves are left out of
+/// the paragraphs, so X8 and the parts of L1 about them never apply.
+pub fn paragraphs(text: &str, direction: Direction) -> Vec<Paragraph> {
+    let mut paragraphs = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((offset, ch)) = chars.next() {
+        if bidi_class_of(ch as u32) != Bc::B {
+            continue;
+        }
+
+        paragraphs.push(paragraph(text, start..offset, direction));
+
+        // CR LF is a single paragraph separator
+        start = match chars.next_if(|(_, next)| ch == '\r' && *next == '\n') {
+            Some((offset, next)) => offset + next.len_utf8(),
+            None => offset + ch.len_utf8(),
+        };
+    }
+
+    if start < text.len() || paragraphs.is_empty() {
+        paragraphs.push(paragraph(text, start..text.len(), direction));
+    }
+
+    paragraphs
+}
+
+fn is_isolate_initiator(class: Bc) -> bool {
+    matches!(class, Bc::Lri | Bc::Rli | Bc::Fsi)
+}
+
+fn is_removed(class: Bc) -> bool {
+    matches!(
+        class,
+        Bc::Rle | Bc::Lre | Bc::Rlo | Bc::Lro | Bc::Pdf | Bc::Bn
+    )
+}
+
+/// Whether the class is a neutral or isolate formatting character, i.e. NI
+/// in the N rules.
+fn is_neutral(class: Bc) -> bool {
+    matches!(
+        class,
+        Bc::S | Bc::Ws | Bc::On | Bc::Fsi | Bc::Lri | Bc::Rli | Bc::Pdi
+    )
+}
+
+/// The strong direction which a class counts as for the N rules, where
+/// numbers count as right-to-left.
+fn strong_direction(class: Bc) -> Option<Bc> {
+    match class {
+        Bc::L => Some(Bc::L),
+        Bc::R | Bc::Al | Bc::En | Bc::An => Some(Bc::R),
+        _ => None,
+    }
+}
+
+fn direction_of_level(level: u8) -> Bc {
+    if level.is_multiple_of(2) {
+        Bc::L
+    } else {
+        Bc::R
+    }
+}
+
+/// Finds the matching PDI of each isolate initiator, as per BD9.
+fn matching_pdis(classes: &[Bc]) -> Vec<Option<usize>> {
+    let mut matches = vec![None; classes.len()];
+    let mut open = Vec::new();
+
+    for (i, class) in classes.iter().enumerate() {
+        if is_isolate_initiator(*class) {
+            open.push(i);
+        } else if *class == Bc::Pdi
+            && let Some(initiator) = open.pop()
+        {
+            matches[initiator] = Some(i);
+        }
+    }
+
+    matches
+}
+
+/// P2 and P3: the level given by the first strong character in the range,
+/// skipping over isolates, or `None` if there is none.
+fn first_strong_level(classes: &[Bc], pdis: &[Option<usize>], range: Range<usize>) -> Option<u8> {
+    let mut i = range.start;
+
+    while i < range.end {
+        match classes[i] {
+            Bc::L => return Some(0),
+            Bc::R | Bc::Al => return Some(1),
+            class if is_isolate_initiator(class) => match pdis[i] {
+                Some(pdi) => i = pdi,
+                None => return None,
+            },
+            _ => {}
+        }
+
+        i += 1;
+    }
+
+    None
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Status {
+    level: u8,
+    override_class: Option<Bc>,
+    isolate: bool,
+}
+
+fn paragraph(text: &str, range: Range<usize>, direction: Direction) -> Paragraph {
+    let chars: Vec<char> = text[range.clone()].chars().collect();
+    let classes: Vec<Bc> = chars.iter().map(|ch| bidi_class_of(*ch as u32)).collect();
+    let pdis = matching_pdis(&classes);
+
+    let level = match direction {
+        Direction::Ltr => 0,
+        Direction::Rtl => 1,
+        Direction::Auto => first_strong_level(&classes, &pdis, 0..chars.len()).unwrap_or(0),
+    };
+
+    let (types, mut levels) = explicit_levels(&classes, &pdis, level);
+    let removed: Vec<bool> = classes.iter().map(|class| is_removed(*class)).collect();
+
+    for sequence in isolating_run_sequences(&classes, &pdis, &levels, &removed, level) {
+        resolve_sequence(&sequence, &chars, &classes, &types, &mut levels);
+    }
+
+    // removed characters take the level of the character before them, so
+    // that they don't split the runs reversed by L2
+    for i in 0..chars.len() {
+        if removed[i] {
+            levels[i] = if i == 0 { level } else { levels[i - 1] };
+        }
+    }
+
+    // L1: segment separators, and whitespace before them or at the end of
+    // the line, are reset to the paragraph level
+    let mut trailing = true;
+
+    for i in (0..chars.len()).rev() {
+        match classes[i] {
+            Bc::S => {
+                levels[i] = level;
+                trailing = true;
+            }
+            class
+                if trailing
+                    && (matches!(class, Bc::Ws | Bc::Pdi)
+                        || is_isolate_initiator(class)
+                        || removed[i]) =>
+            {
+                levels[i] = level;
+            }
+            _ => trailing = false,
+        }
+    }
+
+    Paragraph {
+        range,
+        level,
+        levels,
+        chars,
+        removed,
+    }
+}
+
+/// Applies X1 to X8, returning the class of each character after any
+/// directional overrides, and its explicit embedding level.
+fn explicit_levels(classes: &[Bc], pdis: &[Option<usize>], level: u8) -> (Vec<Bc>, Vec<u8>) {
+    let mut types = classes.to_vec();
+    let mut levels = vec![level; classes.len()];
+
+    let mut stack = vec![Status {
+        level,
+        override_class: None,
+        isolate: false,
+    }];
+    let mut overflow_isolates = 0;
+    let mut overflow_embeddings = 0;
+    let mut valid_isolates = 0;
+
+    for i in 0..classes.len() {
+        let current = *stack.last().unwrap();
+
+        match classes[i] {
+            // X2 to X5
+            class @ (Bc::Rle | Bc::Lre | Bc::Rlo | Bc::Lro) => {
+                levels[i] = current.level;
+
+                let rtl = matches!(class, Bc::Rle | Bc::Rlo);
+                let new_level = next_level(current.level, rtl);
+
+                if new_level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                    stack.push(Status {
+                        level: new_level,
+                        override_class: match class {
+                            Bc::Rlo => Some(Bc::R),
+                            Bc::Lro => Some(Bc::L),
+                            _ => None,
+                        },
+                        isolate: false,
+                    });
+                } else if overflow_isolates == 0 {
+                    overflow_embeddings += 1;
+                }
+            }
+            // X5a to X5c
+            class @ (Bc::Rli | Bc::Lri | Bc::Fsi) => {
+                levels[i] = current.level;
+
+                if let Some(override_class) = current.override_class {
+                    types[i] = override_class;
+                }
+
+                let rtl = match class {
+                    Bc::Rli => true,
+                    Bc::Lri => false,
+                    _ => {
+                        let end = pdis[i].unwrap_or(classes.len());
+                        first_strong_level(classes, pdis, i + 1..end) == Some(1)
+                    }
+                };
+                let new_level = next_level(current.level, rtl);
+
+                if new_level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                    valid_isolates += 1;
+                    stack.push(Status {
+                        level: new_level,
+                        override_class: None,
+                        isolate: true,
+                    });
+                } else {
+                    overflow_isolates += 1;
+                }
+            }
+            // X6a
+            Bc::Pdi => {
+                if overflow_isolates > 0 {
+                    overflow_isolates -= 1;
+                } else if valid_isolates > 0 {
+                    overflow_embeddings = 0;
+
+                    while stack.last().is_some_and(|status| !status.isolate) {
+                        stack.pop();
+                    }
+
+                    stack.pop();
+                    valid_isolates -= 1;
+                }
+
+                let current = *stack.last().unwrap();
+                levels[i] = current.level;
+
+                if let Some(override_class) = current.override_class {
+                    types[i] = override_class;
+                }
+            }
+            // X7
+            Bc::Pdf => {
+                levels[i] = current.level;
+
+                if overflow_isolates == 0 {
+                    if overflow_embeddings > 0 {
+                        overflow_embeddings -= 1;
+                    } else if !current.isolate && stack.len() >= 2 {
+                        stack.pop();
+                    }
+                }
+            }
+            // X9 removes these anyway
+            Bc::Bn => levels[i] = current.level,
+            // X6
+            _ => {
+                levels[i] = current.level;
+
+                if let Some(override_class) = current.override_class {
+                    types[i] = override_class;
+                }
+            }
+        }
+    }
+
+    (types, levels)
+}
+
+/// The least odd or even level greater than the given one.
+fn next_level(level: u8, rtl: bool) -> u8 {
+    match (!level.is_multiple_of(2), rtl) {
+        (true, true) | (false, false) => level + 2,
+        _ => level + 1,
+    }
+}
+
+/// An isolating run sequence, as per BD13, along with the types of the
+/// positions just before and after it.
+struct Sequence {
+    indices: Vec<usize>,
+    level: u8,
+    sos: Bc,
+    eos: Bc,
+}
+
+/// X10: splits the characters which weren't removed into level runs, and
+/// joins those runs across isolates into isolating run sequences.
+fn isolating_run_sequences(
+    classes: &[Bc],
+    pdis: &[Option<usize>],
+    levels: &[u8],
+    removed: &[bool],
+    paragraph_level: u8,
+) -> Vec<Sequence> {
+    let kept: Vec<usize> = (0..classes.len()).filter(|i| !removed[*i]).collect();
+    let mut runs: Vec<Vec<usize>> = Vec::new();
+
+    for (n, i) in kept.iter().enumerate() {
+        match runs.last_mut() {
+            Some(run) if levels[kept[n - 1]] == levels[*i] => run.push(*i),
+            _ => runs.push(vec![*i]),
+        }
+    }
+
+    let mut matched_pdi = vec![false; classes.len()];
+    let mut run_starting_at = vec![None; classes.len()];
+
+    for pdi in pdis.iter().flatten() {
+        matched_pdi[*pdi] = true;
+    }
+
+    for (r, run) in runs.iter().enumerate() {
+        run_starting_at[run[0]] = Some(r);
+    }
+
+    let mut sequences = Vec::new();
+
+    for (r, run) in runs.iter().enumerate() {
+        if matched_pdi[run[0]] {
+            continue;
+        }
+
+        let mut indices = run.clone();
+        let mut last_run = r;
+
+        while let Some(pdi) = indices
+            .last()
+            .filter(|last| is_isolate_initiator(classes[**last]))
+            .and_then(|last| pdis[*last])
+        {
+            match run_starting_at[pdi] {
+                Some(next) => {
+                    indices.extend(&runs[next]);
+                    last_run = next;
+                }
+                None => break,
+            }
+        }
+
+        let first = indices[0];
+        let last = *indices.last().unwrap();
+        let level = levels[first];
+        // the characters next to the sequence are at the ends of the runs
+        // next to its first and last runs
+        let before = r
+            .checked_sub(1)
+            .map(|prev| levels[*runs[prev].last().unwrap()]);
+        let after = if is_isolate_initiator(classes[last]) {
+            None
+        } else {
+            runs.get(last_run + 1).map(|next| levels[next[0]])
+        };
+
+        sequences.push(Sequence {
+            indices,
+            level,
+            sos: direction_of_level(level.max(before.unwrap_or(paragraph_level))),
+            eos: direction_of_level(level.max(after.unwrap_or(paragraph_level))),
+        });
+    }
+
+    sequences
+}
+
+/// Applies the weak type rules W1 to W7, the neutral type rules N0 to N2,
+/// and the implicit level rules I1 and I2 to an isolating run sequence.
+fn resolve_sequence(
+    sequence: &Sequence,
+    chars: &[char],
+    classes: &[Bc],
+    types: &[Bc],
+    levels: &mut [u8],
+) {
+    let indices = &sequence.indices;
+    let mut seq: Vec<Bc> = indices.iter().map(|i| types[*i]).collect();
+
+    // W1
+    for n in 0..seq.len() {
+        if seq[n] == Bc::Nsm {
+            seq[n] = match n.checked_sub(1).map(|m| seq[m]) {
+                None => sequence.sos,
+                Some(prev) if is_isolate_initiator(prev) || prev == Bc::Pdi => Bc::On,
+                Some(prev) => prev,
+            };
+        }
+    }
+
+    // W2 and W3
+    let mut last_strong = sequence.sos;
+
+    for class in seq.iter_mut() {
+        match *class {
+            Bc::L | Bc::R => last_strong = *class,
+            Bc::Al => {
+                last_strong = Bc::Al;
+                *class = Bc::R;
+            }
+            Bc::En if last_strong == Bc::Al => *class = Bc::An,
+            _ => {}
+        }
+    }
+
+    // W4
+    for n in 1..seq.len().saturating_sub(1) {
+        match (seq[n - 1], seq[n], seq[n + 1]) {
+            (Bc::En, Bc::Es | Bc::Cs, Bc::En) => seq[n] = Bc::En,
+            (Bc::An, Bc::Cs, Bc::An) => seq[n] = Bc::An,
+            _ => {}
+        }
+    }
+
+    // W5
+    let mut n = 0;
+
+    while n < seq.len() {
+        if seq[n] != Bc::Et {
+            n += 1;
+            continue;
+        }
+
+        let end = (n..seq.len())
+            .find(|m| seq[*m] != Bc::Et)
+            .unwrap_or(seq.len());
+        let touches_number = (n > 0 && seq[n - 1] == Bc::En) || seq.get(end) == Some(&Bc::En);
+
+        if touches_number {
+            seq[n..end].fill(Bc::En);
+        }
+
+        n = end;
+    }
+
+    // W6
+    for class in seq.iter_mut() {
+        if matches!(class, Bc::Es | Bc::Et | Bc::Cs) {
+            *class = Bc::On;
+        }
+    }
+
+    // W7
+    let mut last_strong = sequence.sos;
+
+    for class in seq.iter_mut() {
+        match *class {
+            Bc::L | Bc::R => last_strong = *class,
+            Bc::En if last_strong == Bc::L => *class = Bc::L,
+            _ => {}
+        }
+    }
+
+    let embedding_direction = direction_of_level(sequence.level);
+
+    // N0
+    for (open, close) in bracket_pairs(indices, chars, &seq) {
+        let inside = seq[open + 1..close]
+            .iter()
+            .filter_map(|class| strong_direction(*class));
+        let mut opposite = false;
+        let mut resolved = None;
+
+        for direction in inside {
+            if direction == embedding_direction {
+                resolved = Some(embedding_direction);
+                break;
+            }
+
+            opposite = true;
+        }
+
+        if resolved.is_none() && opposite {
+            let before = seq[..open]
+                .iter()
+                .rev()
+                .find_map(|class| strong_direction(*class))
+                .unwrap_or(sequence.sos);
+
+            resolved = Some(if before == embedding_direction {
+                embedding_direction
+            } else {
+                before
+            });
+        }
+
+        if let Some(direction) = resolved {
+            for bracket in [open, close] {
+                seq[bracket] = direction;
+
+                // marks which followed the bracket took on its old type in W1
+                for n in bracket + 1..seq.len() {
+                    if classes[indices[n]] != Bc::Nsm {
+                        break;
+                    }
+
+                    seq[n] = direction;
+                }
+            }
+        }
+    }
+
+    // N1 and N2
+    let mut n = 0;
+
+    while n < seq.len() {
+        if !is_neutral(seq[n]) {
+            n += 1;
+            continue;
+        }
+
+        let end = (n..seq.len())
+            .find(|m| !is_neutral(seq[*m]))
+            .unwrap_or(seq.len());
+        let before = n
+            .checked_sub(1)
+            .map_or(Some(sequence.sos), |m| strong_direction(seq[m]));
+        let after = seq
+            .get(end)
+            .map_or(Some(sequence.eos), |class| strong_direction(*class));
+
+        let direction = match (before, after) {
+            (Some(before), Some(after)) if before == after => before,
+            _ => embedding_direction,
+        };
+
+        seq[n..end].fill(direction);
+        n = end;
+    }
+
+    // I1 and I2
+    for (n, i) in indices.iter().enumerate() {
+        let level = levels[*i];
+
+        levels[*i] = match (level.is_multiple_of(2), seq[n]) {
+            (true, Bc::R) => level + 1,
+            (true, Bc::An | Bc::En) => level + 2,
+            (false, Bc::L | Bc::En | Bc::An) => level + 1,
+            _ => level,
+        };
+    }
+}
+
+/// BD16: finds the pairs of brackets in the sequence, as positions in it,
+/// sorted by the position of the opening bracket.
+fn bracket_pairs(indices: &[usize], chars: &[char], seq: &[Bc]) -> Vec<(usize, usize)> {
+    let mut open: Vec<(u32, usize)> = Vec::new();
+    let mut pairs = Vec::new();
+
+    for (n, i) in indices.iter().enumerate() {
+        if seq[n] != Bc::On {
+            continue;
+        }
+
+        let cp = chars[*i] as u32;
+        let Some(bracket) = bidi_paired_bracket_of(cp) else {
+            continue;
+        };
+
+        match bracket.bracket_type {
+            BracketType::Open => {
+                if open.len() == MAX_BRACKET_PAIRS {
+                    break;
+                }
+
+                open.push((canonical_bracket(bracket.pair), n));
+            }
+            BracketType::Close => {
+                let cp = canonical_bracket(cp);
+
+                if let Some(depth) = open.iter().rposition(|(pair, _)| *pair == cp) {
+                    pairs.push((open[depth].1, n));
+                    open.truncate(depth);
+                }
+            }
+        }
+    }
+
+    pairs.sort_unstable();
+    pairs
+}
+
+/// Maps a bracket to the one it is canonically equivalent to, if any, so
+/// that e.g. U+2329 pairs with U+3009.
+fn canonical_bracket(cp: u32) -> u32 {
+    match decomposition_of(cp) {
+        Some(decomposition) if decomposition.tag.is_none() => match decomposition.mapping {
+            [single] => *single,
+            _ => cp,
+        },
+        _ => cp,
+    }
+}
@@ -0,0 +1,258 @@
+use nu_plugin_unicode_ucd::{
+    codegen::{
+        binary_properties::{CORE_PROPERTIES, PROP_LIST},
+        casing::{
+            case_foldings_of, simple_lowercase_of, simple_titlecase_of, simple_uppercase_of,
+            special_casings_of,
+        },
+        unicode_data::canonical_combining_class_of,
+    },
+    types::{casing::CaseFoldingStatus, ranges::RangeTable},
+};
+
+use crate::unicode::segmentation::word::words;
+
+/// One of the three case mappings of a codepoint.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum CaseMapping {
+    Lower,
+    Title,
+    Upper,
+}
+
+/// Returns the primary language subtag of a locale such as `tr-CY` or
+/// `lt_LT`, in lowercase, which is how the conditions in
+/// `SpecialCasing.txt` name languages.
+pub fn language_of(locale: &str) -> String {
+    locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+/// Maps the text to lowercase, as per the toLowercase operation of the
+/// default case algorithms, with the rules for the given language.
+pub fn to_lowercase(text: &str, language: &str) -> String {
+    let context = Context::new(text, language);
+
+    (0..context.chars.len())
+        .map(|idx| context.map(idx, CaseMapping::Lower))
+        .collect()
+}
+
+/// Maps the text to uppercase, as per the toUppercase operation of the
+/// default case algorithms, with the rules for the given language.
+pub fn to_uppercase(text: &str, language: &str) -> String {
+    let context = Context::new(text, language);
+
+    (0..context.chars.len())
+        .map(|idx| context.map(idx, CaseMapping::Upper))
+        .collect()
+}
+
+/// Maps the text to titlecase, as per the toTitlecase operation of the
+/// default case algorithms: the first cased character of each word, as
+/// found by the word boundaries of UAX #29, is mapped to titlecase, and the
+/// rest of the word to lowercase.
+pub fn to_titlecase(text: &str, language: &str) -> String {
+    let context = Context::new(text, language);
+    let mut titlecased = String::with_capacity(text.len());
+    let mut idx = 0;
+
+    for (_, word) in words(text) {
+        let mut found_cased = false;
+
+        for _ in word.chars() {
+            let mapping = if !found_cased && context.is_cased(context.chars[idx]) {
+                found_cased = true;
+                CaseMapping::Title
+            } else {
+                CaseMapping::Lower
+            };
+
+            titlecased.push_str(&context.map(idx, mapping));
+            idx += 1;
+        }
+    }
+
+    titlecased
+}
+
+/// Folds the case of the text with the mappings of `CaseFolding.txt`, for
+/// caseless matching.
+///
+/// The full case folding may map a character to several, e.g. ß to ss,
+/// whereas the simple case folding keeps the length of the text the same.
+/// The Turkic case folding maps I to ı and İ to i, rather than to i and
+/// i̇.
+pub fn casefold(text: &str, simple: bool, turkic: bool) -> String {
+    let mut folded = String::with_capacity(text.len());
+
+    for ch in text.chars() {
+        let foldings = case_foldings_of(ch as u32);
+        let folding = foldings
+            .iter()
+            .find(|folding| turkic && folding.status == CaseFoldingStatus::Turkic)
+            .or_else(|| {
+                foldings.iter().find(|folding| match folding.status {
+                    CaseFoldingStatus::Common => true,
+                    CaseFoldingStatus::Full => !simple,
+                    CaseFoldingStatus::Simple => simple,
+                    CaseFoldingStatus::Turkic => false,
+                })
+            });
+
+        match folding {
+            Some(folding) => {
+                folded.extend(folding.mapping.iter().filter_map(|cp| char::from_u32(*cp)))
+            }
+            None => folded.push(ch),
+        }
+    }
+
+    folded
+}
+
+fn binary_property(
+    properties: &'static [(&str, RangeTable<()>)],
+    name: &str,
+) -> &'static RangeTable<()> {
+    properties
+        .iter()
+        .find(|(property, _)| *property == name)
+        .map(|(_, table)| table)
+        .unwrap_or_else(|| panic!("missing binary property {name}"))
+}
+
+/// The text being case mapped, and what is needed to evaluate the
+/// conditions of `SpecialCasing.txt` for each of its characters.
+struct Context<'a> {
+    chars: Vec<char>,
+    language: &'a str,
+    cased: &'static RangeTable<()>,
+    case_ignorable: &'static RangeTable<()>,
+    soft_dotted: &'static RangeTable<()>,
+}
+
+impl<'a> Context<'a> {
+    fn new(text: &str, language: &'a str) -> Self {
+        Context {
+            chars: text.chars().collect(),
+            language,
+            cased: binary_property(CORE_PROPERTIES, "Cased"),
+            case_ignorable: binary_property(CORE_PROPERTIES, "Case_Ignorable"),
+            soft_dotted: binary_property(PROP_LIST, "Soft_Dotted"),
+        }
+    }
+
+    fn is_cased(&self, ch: char) -> bool {
+        self.cased.contains(ch as u32)
+    }
+
+    fn is_case_ignorable(&self, ch: char) -> bool {
+        self.case_ignorable.contains(ch as u32)
+    }
+
+    /// Maps the character at the index, using the row of `SpecialCasing.txt`
+    /// with the most conditions among those which hold, and the simple case
+    /// mapping if there is none.
+    fn map(&self, idx: usize, mapping: CaseMapping) -> String {
+        let ch = self.chars[idx];
+        let special = special_casings_of(ch as u32)
+            .iter()
+            .filter(|special| {
+                special
+                    .conditions
+                    .iter()
+                    .all(|condition| self.holds(condition, idx))
+            })
+            .max_by_key(|special| special.conditions.len());
+
+        if let Some(special) = special {
+            let mapped = match mapping {
+                CaseMapping::Lower => special.lowercase,
+                CaseMapping::Title => special.titlecase,
+                CaseMapping::Upper => special.uppercase,
+            };
+
+            return mapped.iter().filter_map(|cp| char::from_u32(*cp)).collect();
+        }
+
+        let simple = match mapping {
+            CaseMapping::Lower => simple_lowercase_of(ch as u32),
+            CaseMapping::Title => simple_titlecase_of(ch as u32),
+            CaseMapping::Upper => simple_uppercase_of(ch as u32),
+        };
+
+        simple.and_then(char::from_u32).unwrap_or(ch).to_string()
+    }
+
+    /// Whether a condition from `SpecialCasing.txt` holds for the character
+    /// at the index. Conditions are either casing contexts, possibly negated
+    /// with `Not_`, or language IDs.
+    fn holds(&self, condition: &str, idx: usize) -> bool {
+        match condition {
+            "Final_Sigma" => self.is_final_sigma(idx),
+            "After_Soft_Dotted" => self.preceded_by(idx, |ch| self.soft_dotted.contains(ch as u32)),
+            "More_Above" => {
+                self.followed_by(idx, |ch| canonical_combining_class_of(ch as u32) == 230)
+            }
+            "Before_Dot" => self.followed_by(idx, |ch| ch == '\u{307}'),
+            "After_I" => self.preceded_by(idx, |ch| ch == 'I'),
+            condition => match condition.strip_prefix("Not_") {
+                Some(condition) => !self.holds(condition, idx),
+                None => condition == self.language,
+            },
+        }
+    }
+
+    /// Whether the character is preceded by a cased letter and then any
+    /// number of case-ignorable characters, and not followed by any number
+    /// of case-ignorable characters and then a cased letter.
+    fn is_final_sigma(&self, idx: usize) -> bool {
+        let before = self.chars[..idx]
+            .iter()
+            .rev()
+            .find(|ch| !self.is_case_ignorable(**ch));
+        let after = self.chars[idx + 1..]
+            .iter()
+            .find(|ch| !self.is_case_ignorable(**ch));
+
+        before.is_some_and(|ch| self.is_cased(*ch)) && !after.is_some_and(|ch| self.is_cased(*ch))
+    }
+
+    /// Whether there is a character matching the predicate before the one
+    /// at the index, with no character of combining class 0 or 230 between
+    /// them.
+    fn preceded_by(&self, idx: usize, predicate: impl Fn(char) -> bool) -> bool {
+        for ch in self.chars[..idx].iter().rev() {
+            if predicate(*ch) {
+                return true;
+            }
+
+            if matches!(canonical_combining_class_of(*ch as u32), 0 | 230) {
+                return false;
+            }
+        }
+
+        false
+    }
+
+    /// Whether there is a character matching the predicate after the one at
+    /// the index, with no character of combining class 0 or 230 between
+    /// them.
+    fn followed_by(&self, idx: usize, predicate: impl Fn(char) -> bool) -> bool {
+        for ch in &self.chars[idx + 1..] {
+            if predicate(*ch) {
+                return true;
+            }
+
+            if matches!(canonical_combining_class_of(*ch as u32), 0 | 230) {
+                return false;
+            }
+        }
+
+        false
+    }
+}
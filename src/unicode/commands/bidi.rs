@@ -0,0 +1,164 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+    record,
+};
+
+use crate::{
+    Unicode,
+    unicode::{
+        bidi::{Direction, paragraphs},
+        constants::{
+            self,
+            commands::bidi::{defaults, flags},
+        },
+    },
+};
+
+#[derive(Debug)]
+pub struct UnicodeBidi;
+
+impl UnicodeBidi {
+    pub(crate) fn run_impl(
+        &self,
+        _plugin: &Unicode,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let direction = get_direction(call)?;
+
+        match input {
+            PipelineData::Value(val, meta) => {
+                Ok(PipelineData::Value(Self::bidi(val, direction)?, meta))
+            }
+            PipelineData::ListStream(stream, meta) => {
+                let span = stream.span();
+
+                Ok(PipelineData::ListStream(
+                    stream.map(move |val| {
+                        Self::bidi(val, direction)
+                            .unwrap_or_else(|err| Value::error(ShellError::from(err), span))
+                    }),
+                    meta,
+                ))
+            }
+            data => Err(LabeledError::new("invalid input").with_label(
+                "Only values can be passed as input",
+                data.span().unwrap_or(Span::unknown()),
+            )),
+        }
+    }
+
+    /// Splits the input into paragraphs, and resolves the embedding levels
+    /// and the visual order of each one.
+    pub(crate) fn bidi(val: Value, direction: Direction) -> Result<Value, LabeledError> {
+        let span = val.span();
+
+        match val {
+            Value::String { val, .. } => Ok(paragraphs(&val, direction)
+                .into_iter()
+                .map(|paragraph| {
+                    let levels = paragraph
+                        .levels
+                        .iter()
+                        .map(|level| (*level as i64).into_value(span))
+                        .collect::<Vec<_>>();
+                    let direction = if paragraph.level.is_multiple_of(2) {
+                        "ltr"
+                    } else {
+                        "rtl"
+                    };
+
+                    record!(
+                        "paragraph" => val[paragraph.range.clone()].into_value(span),
+                        "direction" => direction.into_value(span),
+                        "levels" => levels.into_value(span),
+                        "visual" => paragraph.visual().into_value(span),
+                    )
+                    .into_value(span)
+                })
+                .collect::<Vec<_>>()
+                .into_value(span)),
+            Value::List { vals, .. } => Ok(vals
+                .into_iter()
+                .map(|val| Self::bidi(val, direction))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_value(span)),
+            val => {
+                Err(LabeledError::new("Invalid input")
+                    .with_label("Input must be a string", val.span()))
+            }
+        }
+    }
+}
+
+/// Gets the paragraph direction from the `--direction` flag.
+fn get_direction(call: &EvaluatedCall) -> Result<Direction, LabeledError> {
+    let direction = call
+        .get_flag_value(flags::DIRECTION)
+        .unwrap_or(Value::string(defaults::DIRECTION, Span::unknown()));
+    let direction_span = direction.span();
+
+    Direction::from_name(&direction.into_string()?).ok_or_else(|| {
+        LabeledError::new("invalid direction")
+            .with_label("no such paragraph direction", direction_span)
+            .with_help(format!("expected one of {}", Direction::NAMES.join(", ")))
+    })
+}
+
+impl PluginCommand for UnicodeBidi {
+    type Plugin = Unicode;
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        self.run_impl(plugin, engine, call, input)
+    }
+
+    fn name(&self) -> &str {
+        constants::commands::bidi::NAME
+    }
+
+    fn description(&self) -> &str {
+        "Resolves the embedding levels and the visual order of bidirectional text"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Implements the Unicode Bidirectional Algorithm (UAX #9). The input is split into paragraphs, and for each one, the embedding level of every character is resolved, and the paragraph is reordered for display on a single line, with brackets and other mirrored characters replaced by their mirror image in right-to-left runs. Explicit embedding, override and isolate controls are honoured, and left out of the visual order."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::String, Type::Table([].into())),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::Table([].into()))),
+                ),
+            ])
+            .named(
+                flags::DIRECTION,
+                SyntaxShape::String,
+                "The paragraph direction: ltr, rtl, or auto to detect it from the first strong character. Defaults to auto.",
+                Some('d'),
+            )
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec![
+            "unicode",
+            "bidi",
+            "bidirectional",
+            "rtl",
+            "arabic",
+            "hebrew",
+            "reorder",
+            "visual",
+        ]
+    }
+}
@@ -0,0 +1,68 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_plugin_unicode_ucd::codegen::{blocks::BLOCKS, unicode_data::is_assigned};
+use nu_protocol::{IntoValue, LabeledError, ListStream, PipelineData, Signature, Type, record};
+
+use crate::{Unicode, unicode::constants};
+
+#[derive(Debug)]
+pub struct UnicodeBlocks;
+
+impl UnicodeBlocks {
+    pub(crate) fn run_impl(
+        &self,
+        _plugin: &Unicode,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+
+        let blocks = BLOCKS.iter().map(move |(start, end, name)| {
+            let assigned = (*start..=*end).filter(|cp| is_assigned(*cp)).count();
+
+            record!(
+                "start" => start.into_value(span),
+                "end" => end.into_value(span),
+                "assigned" => (assigned as i64).into_value(span),
+                "name" => name.into_value(span),
+            )
+            .into_value(span)
+        });
+
+        Ok(PipelineData::ListStream(
+            ListStream::new(blocks, span, engine.signals().clone()),
+            None,
+        ))
+    }
+}
+
+impl PluginCommand for UnicodeBlocks {
+    type Plugin = Unicode;
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        self.run_impl(plugin, engine, call, input)
+    }
+
+    fn name(&self) -> &str {
+        constants::commands::blocks::NAME
+    }
+
+    fn description(&self) -> &str {
+        "Lists every Unicode block with its range and the number of assigned code points in it"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table([].into()))])
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["unicode", "block", "range"]
+    }
+}
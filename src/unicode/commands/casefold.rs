@@ -0,0 +1,127 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use crate::{
+    Unicode,
+    unicode::{
+        casing::casefold,
+        commands::upcase::get_language,
+        constants::{
+            self,
+            commands::{casefold::flags, upcase::flags::LOCALE},
+        },
+    },
+};
+
+/// The languages which use the Turkic case folding, where I and İ fold to
+/// ı and i.
+const TURKIC_LANGUAGES: &[&str] = &["tr", "az"];
+
+#[derive(Debug)]
+pub struct UnicodeCasefold;
+
+impl UnicodeCasefold {
+    pub(crate) fn run_impl(
+        &self,
+        _plugin: &Unicode,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let simple = call.has_flag(flags::SIMPLE)?;
+        let turkic = TURKIC_LANGUAGES.contains(&get_language(call)?.as_str());
+
+        match input {
+            PipelineData::Value(val, meta) => Ok(PipelineData::Value(
+                Self::casefold(val, simple, turkic)?,
+                meta,
+            )),
+            PipelineData::ListStream(stream, meta) => {
+                let span = stream.span();
+
+                Ok(PipelineData::ListStream(
+                    stream.map(move |val| {
+                        Self::casefold(val, simple, turkic)
+                            .unwrap_or_else(|err| Value::error(ShellError::from(err), span))
+                    }),
+                    meta,
+                ))
+            }
+            data => Err(LabeledError::new("invalid input").with_label(
+                "Only values can be passed as input",
+                data.span().unwrap_or(Span::unknown()),
+            )),
+        }
+    }
+
+    pub(crate) fn casefold(val: Value, simple: bool, turkic: bool) -> Result<Value, LabeledError> {
+        let span = val.span();
+
+        match val {
+            Value::String { val, .. } => Ok(casefold(&val, simple, turkic).into_value(span)),
+            Value::List { vals, .. } => Ok(vals
+                .into_iter()
+                .map(|val| Self::casefold(val, simple, turkic))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_value(span)),
+            val => {
+                Err(LabeledError::new("Invalid input")
+                    .with_label("Input must be a string", val.span()))
+            }
+        }
+    }
+}
+
+impl PluginCommand for UnicodeCasefold {
+    type Plugin = Unicode;
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        self.run_impl(plugin, engine, call, input)
+    }
+
+    fn name(&self) -> &str {
+        constants::commands::casefold::NAME
+    }
+
+    fn description(&self) -> &str {
+        "Folds the case of the input string, for caseless matching"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Case folding maps strings which only differ by case to the same string, using the mappings of `CaseFolding.txt`. By default, the full case folding is applied, which may change the length of the string, e.g. ß folds to ss. With `--simple`, only mappings to a single character are used, so ß is left as is. With a `--locale` of tr or az, the Turkic case folding is applied, where I folds to ı and İ to i."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::String)),
+                ),
+            ])
+            .switch(
+                flags::SIMPLE,
+                "Apply the simple case folding, which maps each character to a single one.",
+                Some('s'),
+            )
+            .named(
+                LOCALE,
+                SyntaxShape::String,
+                "The language whose case folding rules apply. Only tr and az differ from the default rules.",
+                Some('l'),
+            )
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["unicode", "casefold", "case", "caseless", "compare", "fold"]
+    }
+}
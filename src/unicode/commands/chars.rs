@@ -1,29 +1,38 @@
-use std::{
-    fmt::Display,
-    io::{BufRead, BufReader, Cursor, Read},
-};
+use std::{fmt::Display, io::Cursor};
 
-use encoding_rs_io::DecodeReaderBytesBuilder;
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
-use nu_plugin_unicode_ucd::codegen::{name_aliases::NAME_ALIASES, unicode_data::UNICODE_DATA};
+use nu_plugin_unicode_ucd::codegen::{
+    ages::age_of,
+    bidi::{bidi_mirroring_glyph_of, bidi_paired_bracket_of},
+    binary_properties::binary_properties,
+    blocks::{BLOCKS, NO_BLOCK},
+    east_asian_widths::east_asian_width_of,
+    name_aliases::NAME_ALIASES,
+    scripts::{script_extensions_of, script_of},
+    unicode_data::unicode_data,
+};
 use nu_protocol::{
     IntoValue, LabeledError, ListStream, PipelineData, Range, Record, ShellError, Signals,
-    Signature, Span, SyntaxShape, Type, Value,
-    ast::PathMember,
-    casing::Casing,
-    shell_error::io::{self, IoError},
+    Signature, Span, SyntaxShape, Type, Value, ast::PathMember, casing::Casing, record,
 };
 use tracing_subscriber::prelude::*;
 
 use crate::{
     Unicode,
     unicode::{
-        commands::chars::config::Config,
+        commands::chars::{
+            config::{Config, InvalidBytes},
+            decode::DecodeChars,
+        },
         constants::{self, commands::chars::flags},
+        normalization::NormalizationForm,
+        properties::value_names,
+        segmentation::grapheme::graphemes,
     },
 };
 
 pub mod config;
+pub mod decode;
 
 #[derive(Debug)]
 pub struct UnicodeChars;
@@ -64,13 +73,14 @@ impl UnicodeChars {
             }
             PipelineData::ByteStream(stream, meta) => {
                 let span = stream.span();
-                let stream_signals = signals.clone();
                 let reader = match stream.reader() {
                     None => return Ok(PipelineData::empty()),
                     Some(r) => r,
                 };
 
-                let out_stream = decode_bytes(reader, &config, span, stream_signals)?;
+                let out_stream = DecodeChars::new(reader, &config, span).map(move |val| {
+                    val.unwrap_or_else(|err| Value::error(ShellError::from(err), span))
+                });
 
                 Ok(PipelineData::ListStream(
                     ListStream::new(out_stream, span, signals),
@@ -93,11 +103,7 @@ impl UnicodeChars {
             str_val @ Value::String { .. } => {
                 let span = str_val.span();
                 let val = str_val.into_string().unwrap();
-
-                val.chars()
-                    .map(|ch| get_unicode_values(ch, span))
-                    .collect::<Result<Vec<_>, _>>()?
-                    .into_value(Span::unknown())
+                text_records(&val, &Source::of_str(&val), config, span)?.into_value(Span::unknown())
             }
             Value::List { vals, .. } => vals
                 .into_iter()
@@ -106,7 +112,7 @@ impl UnicodeChars {
                 .into_value(Span::unknown()),
             int_val @ Value::Int { val, .. } => {
                 let span = int_val.span();
-                get_unicode_values(val, span)?
+                get_unicode_values(val, config, span)?
             }
             ref range_val @ Value::Range { .. } => {
                 let span = range_val.span();
@@ -131,7 +137,7 @@ impl UnicodeChars {
                 let bytes = binary_val.as_binary().unwrap();
                 let cursor = Cursor::new(bytes);
                 Value::list(
-                    decode_bytes(cursor, config, span, signals.clone())?.collect(),
+                    DecodeChars::new(cursor, config, span).collect::<Result<Vec<_>, _>>()?,
                     Span::unknown(),
                 )
             }
@@ -147,18 +153,179 @@ impl UnicodeChars {
     }
 }
 
-fn get_unicode_values(
+/// Where a code point came from in the input.
+#[derive(Clone, Debug)]
+pub(crate) struct Source {
+    /// The index of the code point in the input.
+    pub char_index: usize,
+    /// The byte offset in the input of the bytes it was decoded from.
+    pub byte_offset: usize,
+    /// The bytes it was decoded from, in the input encoding.
+    pub bytes: Vec<u8>,
+}
+
+impl Source {
+    /// The sources of the code points of a string, which are UTF-8.
+    fn of_str(text: &str) -> Vec<Source> {
+        text.char_indices()
+            .enumerate()
+            .map(|(char_index, (byte_offset, ch))| Source {
+                char_index,
+                byte_offset,
+                bytes: text.as_bytes()[byte_offset..byte_offset + ch.len_utf8()].to_vec(),
+            })
+            .collect()
+    }
+}
+
+/// The records of the code points of the text, with the columns for where
+/// each came from taken from `sources`, which has an entry per code point.
+/// They are grouped by grapheme cluster if the config says so.
+pub(crate) fn text_records(
+    text: &str,
+    sources: &[Source],
+    config: &Config,
+    span: Span,
+) -> Result<Vec<Value>, LabeledError> {
+    let mut sources = sources.iter();
+    let mut char_records = |chars: &str| {
+        chars
+            .chars()
+            .zip(sources.by_ref())
+            .map(|(ch, source)| char_record(ch, source, config, span))
+            .collect::<Result<Vec<_>, LabeledError>>()
+    };
+
+    if config.graphemes {
+        graphemes(text)
+            .map(|(_, cluster)| {
+                Ok(record!(
+                    "grapheme" => cluster.into_value(span),
+                    "chars" => char_records(cluster)?.into_value(span),
+                )
+                .into_value(span))
+            })
+            .collect()
+    } else {
+        char_records(text)
+    }
+}
+
+/// The record for a byte sequence which is not valid in the input encoding,
+/// which has the same columns as the records of code points, with only the
+/// ones for where it came from filled in. It is grouped under a grapheme of
+/// its own if the config says so.
+pub(crate) fn invalid_record(
+    source: &Source,
+    config: &Config,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    let val = with_invalid(
+        with_source(empty_record(config, span)?.into_value(span), source),
+        true,
+    );
+
+    Ok(if config.graphemes {
+        record!(
+            "grapheme" => Value::nothing(span),
+            "chars" => vec![val].into_value(span),
+        )
+        .into_value(span)
+    } else {
+        val
+    })
+}
+
+/// A record with the columns of the records of code points, all left empty.
+fn empty_record(config: &Config, span: Span) -> Result<Record, LabeledError> {
+    Ok(
+        get_unicode_values(char::REPLACEMENT_CHARACTER, config, span)?
+            .into_record()?
+            .into_iter()
+            .map(|(column, _)| (column, Value::nothing(Span::unknown())))
+            .collect(),
+    )
+}
+
+/// The record of a code point which came from the input. Code points which
+/// aren't in `UnicodeData.txt`, such as unassigned ones, still get all of
+/// the columns, with only the codepoint filled in.
+fn char_record(
+    ch: char,
+    source: &Source,
+    config: &Config,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    let mut val = get_unicode_values(ch, config, span)?;
+
+    if val.is_nothing() {
+        let mut record = empty_record(config, span)?;
+        record.insert("codepoint", (ch as i64).into_value(Span::unknown()));
+        val = record.into_value(Span::unknown());
+    }
+
+    let val = with_source(val, source);
+
+    Ok(match config.invalid_bytes {
+        InvalidBytes::Report => with_invalid(val, false),
+        _ => val,
+    })
+}
+
+/// Adds the column for whether the record is for an invalid byte sequence
+/// to the end of it.
+fn with_invalid(mut val: Value, invalid: bool) -> Value {
+    if let Value::Record { val: record, .. } = &mut val {
+        record
+            .to_mut()
+            .push("invalid", invalid.into_value(Span::unknown()));
+    }
+
+    val
+}
+
+/// Adds the columns for where the code point came from in front of its
+/// record.
+fn with_source(val: Value, source: &Source) -> Value {
+    let Value::Record { val: record, .. } = val else {
+        return val;
+    };
+
+    Record::from_iter(
+        [
+            (
+                "char_index".into(),
+                (source.char_index as i64).into_value(Span::unknown()),
+            ),
+            (
+                "byte_offset".into(),
+                (source.byte_offset as i64).into_value(Span::unknown()),
+            ),
+            (
+                "byte_length".into(),
+                (source.bytes.len() as i64).into_value(Span::unknown()),
+            ),
+            (
+                "bytes".into(),
+                Value::binary(source.bytes.clone(), Span::unknown()),
+            ),
+        ]
+        .into_iter()
+        .chain(record.into_owned()),
+    )
+    .into_value(Span::unknown())
+}
+
+pub(crate) fn get_unicode_values(
     ch: impl TryInto<u32, Error = impl Display>,
+    config: &Config,
     span: Span,
 ) -> Result<Value, LabeledError> {
     let ch = ch
         .try_into()
         .map_err(|err| LabeledError::new("invalid char").with_label(err.to_string(), span))?;
 
-    let mut data = UNICODE_DATA
-        .get(&ch)
-        .copied()
-        .cloned()
+    let mut data = unicode_data(ch)
         .map(|data| data.into_value(Span::unknown()))
         .unwrap_or(Value::nothing(Span::unknown()));
 
@@ -187,11 +354,75 @@ fn get_unicode_values(
         let name_idx = record.index_of("name").expect("data without name column");
         let num_cols = record.len();
 
-        let mut new_vals = Vec::with_capacity(num_cols + 1);
+        let mut new_vals = Vec::with_capacity(num_cols + 13);
 
         new_vals.extend(record.drain(..=name_idx));
         new_vals.push(("aliases".into(), aliases));
+
+        let mirrored_idx = record
+            .index_of("bidi_mirrored")
+            .expect("data without bidi_mirrored column");
+
+        new_vals.extend(record.drain(..=mirrored_idx));
+        new_vals.push((
+            "bidi_mirroring_glyph".into(),
+            bidi_mirroring_glyph_of(ch).into_value(Span::unknown()),
+        ));
+        new_vals.push((
+            "bidi_paired_bracket".into(),
+            bidi_paired_bracket_of(ch).map_or(Value::nothing(Span::unknown()), |bracket| {
+                record!(
+                    "codepoint" => bracket.pair.into_value(Span::unknown()),
+                    "type" => bracket.bracket_type.name().into_value(Span::unknown()),
+                )
+                .into_value(Span::unknown())
+            }),
+        ));
         new_vals.extend(record.drain(..));
+        new_vals.push((
+            "block".into(),
+            BLOCKS
+                .get(ch)
+                .copied()
+                .unwrap_or(NO_BLOCK)
+                .into_value(Span::unknown()),
+        ));
+        new_vals.push((
+            "script".into(),
+            value_names("sc", script_of(ch), Span::unknown()),
+        ));
+        new_vals.push((
+            "script_extensions".into(),
+            script_extensions_of(ch)
+                .iter()
+                .map(|script| value_names("sc", script, Span::unknown()))
+                .collect::<Vec<_>>()
+                .into_value(Span::unknown()),
+        ));
+        new_vals.push(("age".into(), age_of(ch).into_value(Span::unknown())));
+        new_vals.push((
+            "east_asian_width".into(),
+            value_names("ea", east_asian_width_of(ch), Span::unknown()),
+        ));
+        for (column, property, form) in [
+            ("nfc_quick_check", "NFC_QC", NormalizationForm::Nfc),
+            ("nfd_quick_check", "NFD_QC", NormalizationForm::Nfd),
+            ("nfkc_quick_check", "NFKC_QC", NormalizationForm::Nfkc),
+            ("nfkd_quick_check", "NFKD_QC", NormalizationForm::Nfkd),
+        ] {
+            new_vals.push((
+                column.into(),
+                value_names(
+                    property,
+                    form.quick_check_of(ch).abbreviation(),
+                    Span::unknown(),
+                ),
+            ));
+        }
+        new_vals.push((
+            "binary_properties".into(),
+            binary_property_values(ch, config.property_flags),
+        ));
 
         data = Record::from_iter(new_vals).into_value(Span::unknown());
     } else {
@@ -206,59 +437,21 @@ fn get_unicode_values(
     Ok(data)
 }
 
-fn decode_bytes<'reader, 'cfg, R: Read + 'reader>(
-    reader: R,
-    config: &'cfg Config,
-    span: Span,
-    stream_signals: Signals,
-) -> Result<impl Iterator<Item = Value> + use<R>, LabeledError> {
-    let mut decoder = BufReader::new(
-        DecodeReaderBytesBuilder::new()
-            .encoding(Some(config.encoding))
-            .bom_override(!config.ignore_bom)
-            .build(reader),
-    );
-
-    let vals = std::iter::from_fn({
-        let config = config.clone();
-
-        move || {
-            let buf = match decoder.fill_buf() {
-                Ok(bytes) => bytes,
-                Err(err) => {
-                    return Some(Value::error(
-                        ShellError::from(IoError::new(io::ErrorKind::from(err), span, None)),
-                        span,
-                    ));
-                }
-            };
-
-            tracing::debug!(phase = "read", input = buf);
-
-            let read_bytes = buf.len();
-
-            if read_bytes == 0 {
-                return None;
-            }
+/// Either the snake case names of the binary properties which are true for the
+/// codepoint, or a record of every binary property with its value.
+fn binary_property_values(ch: u32, as_flags: bool) -> Value {
+    let properties =
+        binary_properties().map(|(name, table)| (name.to_lowercase(), table.contains(ch)));
 
-            let val = UnicodeChars::chars(
-                Value::string(unsafe { String::from_utf8_unchecked(buf.to_vec()) }, span),
-                &config,
-                &stream_signals,
-            )
-            .unwrap_or_else(|err| Value::error(ShellError::from(err), span));
-
-            decoder.consume(read_bytes);
-
-            Some(val)
-        }
-    })
-    .flat_map(|val| match val {
-        Value::List { vals, .. } => vals,
-        _ => vec![val],
-    });
-
-    Ok(vals)
+    if as_flags {
+        Record::from_iter(properties.map(|(name, val)| (name, val.into_value(Span::unknown()))))
+            .into_value(Span::unknown())
+    } else {
+        properties
+            .filter_map(|(name, val)| val.then_some(name))
+            .collect::<Vec<_>>()
+            .into_value(Span::unknown())
+    }
 }
 
 impl PluginCommand for UnicodeChars {
@@ -291,6 +484,10 @@ impl PluginCommand for UnicodeChars {
         ])
         .named(flags::ENCODING, SyntaxShape::String, "Encoding of the input bytes. By default, BOM sniffing occurs to detect the encoding; failing that, UTF-8 is assumed.", Some('e'))
         .switch(flags::IGNORE_BOM, "Ignore the BOM, if present. By default, even if an encoding is specified, if a BOM is present, the encoding from the command line is ignored.", Some('b'))
+        .switch(flags::GRAPHEMES, "Group the code points under the extended grapheme clusters they belong to.", Some('g'))
+        .switch(flags::STRICT, "Fail at the first byte sequence which is not valid in the input encoding, rather than replacing it with U+FFFD.", Some('s'))
+        .switch(flags::REPORT_INVALID, "Return a record with `invalid: true`, the bytes and their byte offset for each byte sequence which is not valid in the input encoding, rather than replacing it with U+FFFD. The records of code points get `invalid: false`.", Some('r'))
+        .switch(flags::PROPERTY_FLAGS, "Return the binary properties from PropList.txt, DerivedCoreProperties.txt and emoji-data.txt as a record of booleans, rather than a list of the ones which are true.", Some('p'))
     }
 
     fn examples(&self) -> Vec<nu_protocol::Example<'static>> {
@@ -8,6 +8,36 @@ use crate::unicode::constants::commands::chars::{defaults, flags};
 pub struct Config {
     pub encoding: &'static Encoding,
     pub ignore_bom: bool,
+    /// Return the binary properties as a record of booleans rather than a
+    /// list of the true ones.
+    pub property_flags: bool,
+    /// Group the records of the code points by extended grapheme cluster.
+    pub graphemes: bool,
+    pub invalid_bytes: InvalidBytes,
+}
+
+/// What to do with byte sequences which are not valid in the input encoding.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum InvalidBytes {
+    /// Replace each of them with U+FFFD.
+    #[default]
+    Replace,
+    /// Fail at the first of them.
+    Strict,
+    /// Return a record for each of them, and carry on.
+    Report,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            encoding: encoding_rs::UTF_8,
+            ignore_bom: false,
+            property_flags: false,
+            graphemes: false,
+            invalid_bytes: InvalidBytes::Replace,
+        }
+    }
 }
 
 impl TryFrom<&EvaluatedCall> for Config {
@@ -15,6 +45,26 @@ impl TryFrom<&EvaluatedCall> for Config {
 
     fn try_from(call: &EvaluatedCall) -> Result<Self, Self::Error> {
         let ignore_bom = call.has_flag(flags::IGNORE_BOM)?;
+        let property_flags = call.has_flag(flags::PROPERTY_FLAGS)?;
+        let graphemes = call.has_flag(flags::GRAPHEMES)?;
+        let invalid_bytes = match (
+            call.has_flag(flags::STRICT)?,
+            call.has_flag(flags::REPORT_INVALID)?,
+        ) {
+            (false, false) => InvalidBytes::Replace,
+            (true, false) => InvalidBytes::Strict,
+            (false, true) => InvalidBytes::Report,
+            (true, true) => {
+                return Err(LabeledError::new("conflicting flags").with_label(
+                    format!(
+                        "--{} and --{} can't be used together",
+                        flags::STRICT,
+                        flags::REPORT_INVALID
+                    ),
+                    call.head,
+                ));
+            }
+        };
 
         let encoding_name = call
             .get_flag_value(flags::ENCODING)
@@ -31,6 +81,9 @@ impl TryFrom<&EvaluatedCall> for Config {
         Ok(Config {
             encoding,
             ignore_bom,
+            property_flags,
+            graphemes,
+            invalid_bytes,
         })
     }
 }
@@ -0,0 +1,310 @@
+use std::{
+    collections::VecDeque,
+    io::{ErrorKind, Read},
+};
+
+use encoding_rs::{Decoder, DecoderResult, Encoding};
+use nu_protocol::{
+    LabeledError, ShellError, Span, Value,
+    shell_error::io::{self, IoError},
+};
+
+use crate::unicode::{
+    commands::chars::{
+        Source,
+        config::{Config, InvalidBytes},
+        invalid_record, text_records,
+    },
+    segmentation::grapheme::graphemes,
+};
+
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// How many bytes of the previous chunk are kept, since the code point or
+/// malformed sequence being decoded may have started in it. No encoding has
+/// longer sequences than this.
+const TAIL_SIZE: usize = 16;
+
+/// The length of the longest BOM.
+const MAX_BOM_LEN: usize = 3;
+
+/// Decodes bytes into the records of their code points, reading them in
+/// chunks so that streams are never held in memory as a whole.
+///
+/// The bytes are fed to the decoder one at a time, so that each code point
+/// can be traced back to the bytes it was decoded from. Byte sequences which
+/// are not valid in the input encoding are replaced with U+FFFD, reported as
+/// an error, or reported as a record of their own, as per
+/// [`Config::invalid_bytes`].
+pub(crate) struct DecodeChars<R> {
+    reader: R,
+    /// The decoder for the input encoding, which is only known once the BOM,
+    /// if any, has been read.
+    decoder: Option<Decoder>,
+    config: Config,
+    span: Span,
+    /// The bytes of the current chunk, after the tail of the previous one.
+    chunk: Vec<u8>,
+    /// The byte offset in the input of the start of `chunk`.
+    chunk_offset: usize,
+    /// The byte offset in the input of the code point being decoded.
+    char_offset: usize,
+    /// The index of the next code point.
+    char_index: usize,
+    /// When grouping by grapheme, the last cluster of each chunk may carry
+    /// on into the next chunk, so it is held back until that is read.
+    carry: (String, Vec<Source>),
+    pending: VecDeque<Result<Value, LabeledError>>,
+    done: bool,
+}
+
+impl<R: Read> DecodeChars<R> {
+    pub(crate) fn new(reader: R, config: &Config, span: Span) -> Self {
+        DecodeChars {
+            reader,
+            decoder: None,
+            config: config.clone(),
+            span,
+            chunk: Vec::new(),
+            chunk_offset: 0,
+            char_offset: 0,
+            char_index: 0,
+            carry: (String::new(), Vec::new()),
+            pending: VecDeque::new(),
+            done: false,
+        }
+    }
+
+    /// Reads up to another chunk of bytes onto the end of `chunk`, returning
+    /// how many were read.
+    fn read_more(&mut self) -> Result<usize, LabeledError> {
+        let start = self.chunk.len();
+        self.chunk.resize(start + CHUNK_SIZE, 0);
+
+        let read_bytes = loop {
+            match self.reader.read(&mut self.chunk[start..]) {
+                Ok(read_bytes) => break read_bytes,
+                Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                Err(err) => {
+                    self.chunk.truncate(start);
+                    return Err(ShellError::from(IoError::new(
+                        io::ErrorKind::from(err),
+                        self.span,
+                        None,
+                    ))
+                    .into());
+                }
+            }
+        };
+
+        tracing::debug!(phase = "read", input = ?&self.chunk[start..start + read_bytes]);
+
+        self.chunk.truncate(start + read_bytes);
+        Ok(read_bytes)
+    }
+
+    /// Picks the decoder from the BOM at the start of `chunk`, if there is
+    /// one, and returns the length of the BOM, which is left out of the
+    /// decoded text.
+    ///
+    /// By default, a BOM overrides the input encoding, as with
+    /// `encoding_rs::Encoding::new_decoder`. When ignoring the BOM, it is
+    /// only left out if it is the BOM of the input encoding.
+    fn detect_bom(&mut self) -> usize {
+        let (encoding, bom_len) = match Encoding::for_bom(&self.chunk) {
+            Some((encoding, bom_len))
+                if !self.config.ignore_bom || encoding == self.config.encoding =>
+            {
+                (encoding, bom_len)
+            }
+            _ => (self.config.encoding, 0),
+        };
+
+        self.decoder = Some(encoding.new_decoder_without_bom_handling());
+        bom_len
+    }
+
+    fn read_chunk(&mut self) -> Result<(), LabeledError> {
+        let tail_start = self.chunk.len().saturating_sub(TAIL_SIZE);
+        self.chunk.drain(..tail_start);
+        self.chunk_offset += tail_start;
+
+        let mut pos = self.chunk.len();
+        let mut read_bytes = self.read_more()?;
+
+        if self.decoder.is_none() {
+            let mut more = read_bytes;
+
+            while more != 0 && self.chunk.len() < MAX_BOM_LEN {
+                more = self.read_more()?;
+                read_bytes += more;
+            }
+
+            pos += self.detect_bom();
+            self.char_offset = self.chunk_offset + pos;
+        }
+
+        let mut decoder = self.decoder.take().expect("decoder without BOM detection");
+        let last = read_bytes == 0;
+        let (mut text, mut sources) = std::mem::take(&mut self.carry);
+
+        loop {
+            if pos == self.chunk.len() && !last {
+                break;
+            }
+
+            let end = (pos + 1).min(self.chunk.len());
+            let decoded_len = text.len();
+
+            text.reserve(
+                decoder
+                    .max_utf8_buffer_length_without_replacement(end - pos)
+                    .unwrap_or(CHUNK_SIZE),
+            );
+
+            let (result, read) = decoder.decode_to_string_without_replacement(
+                &self.chunk[pos..end],
+                &mut text,
+                last,
+            );
+            pos += read;
+
+            if text.len() > decoded_len {
+                // a byte sequence may decode to several code points, which
+                // then all come from the same bytes
+                let source_end = self.chunk_offset + pos;
+                let bytes = self.source_bytes(self.char_offset, source_end);
+
+                for _ in text[decoded_len..].chars() {
+                    sources.push(Source {
+                        char_index: self.char_index,
+                        byte_offset: self.char_offset,
+                        bytes: bytes.clone(),
+                    });
+                    self.char_index += 1;
+                }
+
+                self.char_offset = source_end;
+            }
+
+            let (len, extra) = match result {
+                DecoderResult::InputEmpty if last && pos == self.chunk.len() => break,
+                DecoderResult::InputEmpty | DecoderResult::OutputFull => continue,
+                DecoderResult::Malformed(len, extra) => (len as usize, extra as usize),
+            };
+
+            // the decoder has read `extra` bytes past the malformed sequence
+            let source_end = self.chunk_offset + pos - extra;
+            let offset = source_end.saturating_sub(len);
+            let bytes = self.source_bytes(offset, source_end);
+            self.char_offset = source_end;
+
+            match self.config.invalid_bytes {
+                InvalidBytes::Replace => {
+                    text.push(char::REPLACEMENT_CHARACTER);
+                    sources.push(Source {
+                        char_index: self.char_index,
+                        byte_offset: offset,
+                        bytes,
+                    });
+                    self.char_index += 1;
+                }
+                InvalidBytes::Strict => {
+                    self.emit(text, sources, true);
+                    self.done = true;
+                    return Err(self.invalid_bytes_error(&decoder, &bytes, offset));
+                }
+                InvalidBytes::Report => {
+                    self.emit(
+                        std::mem::take(&mut text),
+                        std::mem::take(&mut sources),
+                        true,
+                    );
+
+                    // the sequence takes the index which its U+FFFD would
+                    // have had
+                    let source = Source {
+                        char_index: self.char_index,
+                        byte_offset: offset,
+                        bytes,
+                    };
+                    self.pending
+                        .push_back(invalid_record(&source, &self.config, self.span));
+                    self.char_index += 1;
+                }
+            }
+        }
+
+        self.decoder = Some(decoder);
+        self.done = last;
+        self.emit(text, sources, last);
+        Ok(())
+    }
+
+    /// The bytes of the input from `start` to `end`, as far as they are still
+    /// in `chunk`.
+    fn source_bytes(&self, start: usize, end: usize) -> Vec<u8> {
+        let start = start.max(self.chunk_offset) - self.chunk_offset;
+        self.chunk[start..end - self.chunk_offset].to_vec()
+    }
+
+    /// Queues up the records for the decoded text. Unless `flush` is set,
+    /// the last grapheme cluster is held back when grouping by grapheme.
+    fn emit(&mut self, mut text: String, mut sources: Vec<Source>, flush: bool) {
+        if self.config.graphemes && !flush {
+            let last_cluster = graphemes(&text).last().map_or(0, |(offset, _)| offset);
+            let carried_sources = sources.split_off(text[..last_cluster].chars().count());
+            self.carry = (text.split_off(last_cluster), carried_sources);
+        }
+
+        if text.is_empty() {
+            return;
+        }
+
+        match text_records(&text, &sources, &self.config, self.span) {
+            Ok(vals) => self.pending.extend(vals.into_iter().map(Ok)),
+            Err(err) => self.pending.push_back(Err(err)),
+        }
+    }
+
+    fn invalid_bytes_error(&self, decoder: &Decoder, bytes: &[u8], offset: usize) -> LabeledError {
+        let hex = bytes
+            .iter()
+            .map(|byte| format!("{byte:02X}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        LabeledError::new("invalid byte sequence")
+            .with_label(
+                format!(
+                    "the bytes [{hex}] at byte offset {offset} are not valid {}",
+                    decoder.encoding().name()
+                ),
+                self.span,
+            )
+            .with_help(
+                "leave out --strict to replace invalid sequences with U+FFFD, or use --report-invalid to list them",
+            )
+    }
+}
+
+impl<R: Read> Iterator for DecodeChars<R> {
+    type Item = Result<Value, LabeledError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(val) = self.pending.pop_front() {
+                return Some(val);
+            }
+
+            if self.done {
+                return None;
+            }
+
+            if let Err(err) = self.read_chunk() {
+                self.pending.push_back(Err(err));
+                self.done = true;
+            }
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_plugin_unicode_ucd::codegen::ages::{UNASSIGNED_AGE, age_of, parse_version};
+use nu_protocol::{
+    IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type,
+    Value, record,
+};
+
+use crate::{Unicode, unicode::constants};
+
+#[derive(Debug)]
+pub struct UnicodeCheckVersion;
+
+impl UnicodeCheckVersion {
+    pub(crate) fn run_impl(
+        &self,
+        _plugin: &Unicode,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let version: Spanned<String> = call.req(0)?;
+        let max_version = parse_version(&version.item).ok_or_else(|| {
+            LabeledError::new("invalid version")
+                .with_label("expected a Unicode version, like 9.0", version.span)
+        })?;
+
+        match input {
+            PipelineData::Value(val, meta) => Ok(PipelineData::Value(
+                Self::check_version(val, max_version)?,
+                meta,
+            )),
+            PipelineData::ListStream(stream, meta) => {
+                let span = stream.span();
+
+                Ok(PipelineData::ListStream(
+                    stream.map(move |val| {
+                        Self::check_version(val, max_version)
+                            .unwrap_or_else(|err| Value::error(ShellError::from(err), span))
+                    }),
+                    meta,
+                ))
+            }
+            data => Err(LabeledError::new("invalid input").with_label(
+                "Only values can be passed as input",
+                data.span().unwrap_or(Span::unknown()),
+            )),
+        }
+    }
+
+    /// Lists the characters of the input which were not yet assigned in the
+    /// given version of Unicode.
+    pub(crate) fn check_version(
+        val: Value,
+        max_version: (u32, u32),
+    ) -> Result<Value, LabeledError> {
+        let span = val.span();
+
+        match val {
+            Value::String { val, .. } => Ok(val
+                .chars()
+                .enumerate()
+                .filter_map(|(index, ch)| {
+                    let age = age_of(ch as u32);
+
+                    // unassigned characters are newer than any version
+                    let newer = age == UNASSIGNED_AGE
+                        || parse_version(age).is_none_or(|version| version > max_version);
+
+                    newer.then(|| {
+                        record!(
+                            "index" => (index as i64).into_value(span),
+                            "char" => ch.into_value(span),
+                            "codepoint" => (ch as u32).into_value(span),
+                            "age" => age.into_value(span),
+                        )
+                        .into_value(span)
+                    })
+                })
+                .collect::<Vec<_>>()
+                .into_value(span)),
+            Value::List { vals, .. } => Ok(vals
+                .into_iter()
+                .map(|val| Self::check_version(val, max_version))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_value(span)),
+            val => {
+                Err(LabeledError::new("Invalid input")
+                    .with_label("Input must be a string", val.span()))
+            }
+        }
+    }
+}
+
+impl PluginCommand for UnicodeCheckVersion {
+    type Plugin = Unicode;
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        self.run_impl(plugin, engine, call, input)
+    }
+
+    fn name(&self) -> &str {
+        constants::commands::check_version::NAME
+    }
+
+    fn description(&self) -> &str {
+        "Lists the characters in the input which are newer than the given version of Unicode"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Each character is reported with its index in the string and the version of Unicode which introduced it. Unassigned code points are always reported, with an age of NA."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::String, Type::Table([].into())),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::Table([].into()))),
+                ),
+            ])
+            .required(
+                "version",
+                SyntaxShape::String,
+                "The newest version of Unicode the characters may be from, e.g. 9.0.",
+            )
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["unicode", "age", "version", "compatibility"]
+    }
+}
@@ -0,0 +1,173 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+    record,
+};
+
+use crate::{
+    Unicode,
+    unicode::{
+        comparison::{Equivalence, compare},
+        constants::{
+            self,
+            commands::compare::{defaults, flags},
+        },
+    },
+};
+
+#[derive(Debug)]
+pub struct UnicodeCompare;
+
+impl UnicodeCompare {
+    pub(crate) fn run_impl(
+        &self,
+        _plugin: &Unicode,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let other: String = call.req(0)?;
+        let equivalence = get_equivalence(call)?;
+
+        match input {
+            PipelineData::Value(val, meta) => Ok(PipelineData::Value(
+                Self::compare(val, &other, equivalence)?,
+                meta,
+            )),
+            PipelineData::ListStream(stream, meta) => {
+                let span = stream.span();
+
+                Ok(PipelineData::ListStream(
+                    stream.map(move |val| {
+                        Self::compare(val, &other, equivalence)
+                            .unwrap_or_else(|err| Value::error(ShellError::from(err), span))
+                    }),
+                    meta,
+                ))
+            }
+            data => Err(LabeledError::new("invalid input").with_label(
+                "Only values can be passed as input",
+                data.span().unwrap_or(Span::unknown()),
+            )),
+        }
+    }
+
+    /// Compares the input with the other string, reporting whether they are
+    /// equivalent, and if not, where each of them starts to differ.
+    pub(crate) fn compare(
+        val: Value,
+        other: &str,
+        equivalence: Equivalence,
+    ) -> Result<Value, LabeledError> {
+        let span = val.span();
+
+        match val {
+            Value::String { val, .. } => {
+                let comparison = compare(&val, other, equivalence);
+                let offsets = |text: &str, offset: Option<usize>| match offset {
+                    Some(offset) => (
+                        (offset as i64).into_value(span),
+                        (text[..offset].chars().count() as i64).into_value(span),
+                    ),
+                    None => (Value::nothing(span), Value::nothing(span)),
+                };
+                let (byte_offset, char_offset) =
+                    offsets(&val, comparison.mismatch.map(|(left, _)| left));
+                let (other_byte_offset, other_char_offset) =
+                    offsets(other, comparison.mismatch.map(|(_, right)| right));
+
+                Ok(record!(
+                    "equal" => comparison.equal.into_value(span),
+                    "byte_offset" => byte_offset,
+                    "char_offset" => char_offset,
+                    "other_byte_offset" => other_byte_offset,
+                    "other_char_offset" => other_char_offset,
+                )
+                .into_value(span))
+            }
+            Value::List { vals, .. } => Ok(vals
+                .into_iter()
+                .map(|val| Self::compare(val, other, equivalence))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_value(span)),
+            val => {
+                Err(LabeledError::new("Invalid input")
+                    .with_label("Input must be a string", val.span()))
+            }
+        }
+    }
+}
+
+/// Gets the equivalence to compare under from the `--mode` flag.
+fn get_equivalence(call: &EvaluatedCall) -> Result<Equivalence, LabeledError> {
+    let mode = call
+        .get_flag_value(flags::MODE)
+        .unwrap_or(Value::string(defaults::MODE, Span::unknown()));
+    let mode_span = mode.span();
+
+    Equivalence::from_name(&mode.into_string()?).ok_or_else(|| {
+        LabeledError::new("invalid mode")
+            .with_label("no such comparison mode", mode_span)
+            .with_help(format!("expected one of {}", Equivalence::NAMES.join(", ")))
+    })
+}
+
+impl PluginCommand for UnicodeCompare {
+    type Plugin = Unicode;
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        self.run_impl(plugin, engine, call, input)
+    }
+
+    fn name(&self) -> &str {
+        constants::commands::compare::NAME
+    }
+
+    fn description(&self) -> &str {
+        "Compares the input string with another one, under canonical equivalence, case folding, or some other equivalence"
+    }
+
+    fn extra_description(&self) -> &str {
+        "The modes are exact, where the codepoints must be the same, canonical, where the NFD must be the same, so that e.g. a precomposed é matches an e followed by a combining acute accent, compatibility, where the NFKD must be the same, caseless, where the NFD of the full case folding of the NFD must be the same, so that differences in both case and composition are ignored, and identifier, the identifier caseless match of UAX #31, where the NFKC_Casefold of the NFD must be the same. Where the strings differ, the offsets of the first grapheme cluster from which each of them differs are reported too, and everything before them is equivalent."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::String, Type::Record([].into())),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::Record([].into()))),
+                ),
+            ])
+            .required(
+                "other",
+                SyntaxShape::String,
+                "The string to compare the input with.",
+            )
+            .named(
+                flags::MODE,
+                SyntaxShape::String,
+                "The equivalence to compare under: exact, canonical, compatibility, caseless or identifier. Defaults to canonical.",
+                Some('m'),
+            )
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec![
+            "unicode",
+            "compare",
+            "equal",
+            "equivalence",
+            "caseless",
+            "normalization",
+            "dedupe",
+        ]
+    }
+}
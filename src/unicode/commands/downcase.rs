@@ -0,0 +1,120 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use crate::{
+    Unicode,
+    unicode::{
+        casing::to_lowercase,
+        commands::upcase::get_language,
+        constants::{self, commands::upcase::flags},
+    },
+};
+
+#[derive(Debug)]
+pub struct UnicodeDowncase;
+
+impl UnicodeDowncase {
+    pub(crate) fn run_impl(
+        &self,
+        _plugin: &Unicode,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let language = get_language(call)?;
+
+        match input {
+            PipelineData::Value(val, meta) => {
+                Ok(PipelineData::Value(Self::downcase(val, &language)?, meta))
+            }
+            PipelineData::ListStream(stream, meta) => {
+                let span = stream.span();
+
+                Ok(PipelineData::ListStream(
+                    stream.map(move |val| {
+                        Self::downcase(val, &language)
+                            .unwrap_or_else(|err| Value::error(ShellError::from(err), span))
+                    }),
+                    meta,
+                ))
+            }
+            data => Err(LabeledError::new("invalid input").with_label(
+                "Only values can be passed as input",
+                data.span().unwrap_or(Span::unknown()),
+            )),
+        }
+    }
+
+    pub(crate) fn downcase(val: Value, language: &str) -> Result<Value, LabeledError> {
+        let span = val.span();
+
+        match val {
+            Value::String { val, .. } => Ok(to_lowercase(&val, language).into_value(span)),
+            Value::List { vals, .. } => Ok(vals
+                .into_iter()
+                .map(|val| Self::downcase(val, language))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_value(span)),
+            val => {
+                Err(LabeledError::new("Invalid input")
+                    .with_label("Input must be a string", val.span()))
+            }
+        }
+    }
+}
+
+impl PluginCommand for UnicodeDowncase {
+    type Plugin = Unicode;
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        self.run_impl(plugin, engine, call, input)
+    }
+
+    fn name(&self) -> &str {
+        constants::commands::downcase::NAME
+    }
+
+    fn description(&self) -> &str {
+        "Maps the input string to lowercase"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Unlike `str downcase`, the full and conditional case mappings of `SpecialCasing.txt` are applied, so that e.g. a Greek capital sigma becomes a final sigma at the end of a word. With `--locale`, the rules for that language are applied too: in Turkish and Azerbaijani, I becomes ı and İ becomes i, and in Lithuanian, a combining dot above is added to i and j when they are followed by other accents, so that they keep their dot."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::String)),
+                ),
+            ])
+            .named(
+                flags::LOCALE,
+                SyntaxShape::String,
+                "The language whose case mapping rules apply, e.g. tr, az or lt. Other languages use the default rules.",
+                Some('l'),
+            )
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec![
+            "unicode",
+            "downcase",
+            "lowercase",
+            "case",
+            "sigma",
+            "locale",
+        ]
+    }
+}
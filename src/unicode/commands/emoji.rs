@@ -0,0 +1,116 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, Type, Value, record,
+};
+
+use crate::{
+    Unicode,
+    unicode::{constants, emoji::emoji_sequences},
+};
+
+#[derive(Debug)]
+pub struct UnicodeEmoji;
+
+impl UnicodeEmoji {
+    pub(crate) fn run_impl(
+        &self,
+        _plugin: &Unicode,
+        _engine: &EngineInterface,
+        _call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        match input {
+            PipelineData::Value(val, meta) => Ok(PipelineData::Value(Self::emoji(val)?, meta)),
+            PipelineData::ListStream(stream, meta) => {
+                let span = stream.span();
+
+                Ok(PipelineData::ListStream(
+                    stream.map(move |val| {
+                        Self::emoji(val)
+                            .unwrap_or_else(|err| Value::error(ShellError::from(err), span))
+                    }),
+                    meta,
+                ))
+            }
+            data => Err(LabeledError::new("invalid input").with_label(
+                "Only values can be passed as input",
+                data.span().unwrap_or(Span::unknown()),
+            )),
+        }
+    }
+
+    /// Lists the emoji sequences in the input.
+    pub(crate) fn emoji(val: Value) -> Result<Value, LabeledError> {
+        let span = val.span();
+
+        match val {
+            Value::String { val, .. } => Ok(emoji_sequences(&val)
+                .into_iter()
+                .map(|sequence| {
+                    record!(
+                        "index" => (sequence.index as i64).into_value(span),
+                        "emoji" => sequence.text.into_value(span),
+                        "type" => sequence.kind.name().into_value(span),
+                        "codepoints" => sequence
+                            .text
+                            .chars()
+                            .map(|ch| ch as u32)
+                            .collect::<Vec<_>>()
+                            .into_value(span),
+                    )
+                    .into_value(span)
+                })
+                .collect::<Vec<_>>()
+                .into_value(span)),
+            Value::List { vals, .. } => Ok(vals
+                .into_iter()
+                .map(Self::emoji)
+                .collect::<Result<Vec<_>, _>>()?
+                .into_value(span)),
+            val => {
+                Err(LabeledError::new("Invalid input")
+                    .with_label("Input must be a string", val.span()))
+            }
+        }
+    }
+}
+
+impl PluginCommand for UnicodeEmoji {
+    type Plugin = Unicode;
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        self.run_impl(plugin, engine, call, input)
+    }
+
+    fn name(&self) -> &str {
+        constants::commands::emoji::NAME
+    }
+
+    fn description(&self) -> &str {
+        "Splits the emoji out of the input string, as whole emoji sequences"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Sequences are recognized as per UTS #51. Each is reported with the index of its first character, its type (basic, presentation, keycap, flag, modifier, tag or zwj) and its code points. Characters which are displayed as text by default are only reported when they are followed by the emoji presentation selector or are part of a longer sequence."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name()).input_output_types(vec![
+            (Type::String, Type::Table([].into())),
+            (
+                Type::List(Box::new(Type::String)),
+                Type::List(Box::new(Type::Table([].into()))),
+            ),
+        ])
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["unicode", "emoji", "sequence", "zwj", "flag"]
+    }
+}
@@ -0,0 +1,116 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, Type, Value, record,
+};
+
+use crate::{
+    Unicode,
+    unicode::{constants, segmentation::grapheme::graphemes},
+};
+
+#[derive(Debug)]
+pub struct UnicodeGraphemes;
+
+impl UnicodeGraphemes {
+    pub(crate) fn run_impl(
+        &self,
+        _plugin: &Unicode,
+        _engine: &EngineInterface,
+        _call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        match input {
+            PipelineData::Value(val, meta) => Ok(PipelineData::Value(Self::graphemes(val)?, meta)),
+            PipelineData::ListStream(stream, meta) => {
+                let span = stream.span();
+
+                Ok(PipelineData::ListStream(
+                    stream.map(move |val| {
+                        Self::graphemes(val)
+                            .unwrap_or_else(|err| Value::error(ShellError::from(err), span))
+                    }),
+                    meta,
+                ))
+            }
+            data => Err(LabeledError::new("invalid input").with_label(
+                "Only values can be passed as input",
+                data.span().unwrap_or(Span::unknown()),
+            )),
+        }
+    }
+
+    /// Splits the input into its extended grapheme clusters.
+    pub(crate) fn graphemes(val: Value) -> Result<Value, LabeledError> {
+        let span = val.span();
+
+        match val {
+            Value::String { val, .. } => {
+                let mut char_offset = 0;
+
+                Ok(graphemes(&val)
+                    .map(|(byte_offset, cluster)| {
+                        let val = record!(
+                            "grapheme" => cluster.into_value(span),
+                            "byte_offset" => (byte_offset as i64).into_value(span),
+                            "char_offset" => (char_offset as i64).into_value(span),
+                        )
+                        .into_value(span);
+
+                        char_offset += cluster.chars().count();
+                        val
+                    })
+                    .collect::<Vec<_>>()
+                    .into_value(span))
+            }
+            Value::List { vals, .. } => Ok(vals
+                .into_iter()
+                .map(Self::graphemes)
+                .collect::<Result<Vec<_>, _>>()?
+                .into_value(span)),
+            val => {
+                Err(LabeledError::new("Invalid input")
+                    .with_label("Input must be a string", val.span()))
+            }
+        }
+    }
+}
+
+impl PluginCommand for UnicodeGraphemes {
+    type Plugin = Unicode;
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        self.run_impl(plugin, engine, call, input)
+    }
+
+    fn name(&self) -> &str {
+        constants::commands::graphemes::NAME
+    }
+
+    fn description(&self) -> &str {
+        "Splits the input string into extended grapheme clusters, i.e. user-perceived characters"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Clusters are found as per UAX #29, including the rules for Indic conjuncts and emoji sequences. Each is reported with the byte offset and the code point offset it starts at."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name()).input_output_types(vec![
+            (Type::String, Type::Table([].into())),
+            (
+                Type::List(Box::new(Type::String)),
+                Type::List(Box::new(Type::Table([].into()))),
+            ),
+        ])
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["unicode", "grapheme", "cluster", "segmentation", "split"]
+    }
+}
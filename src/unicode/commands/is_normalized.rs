@@ -0,0 +1,122 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use crate::{
+    Unicode,
+    unicode::{
+        commands::normalize::get_form,
+        constants::{self, commands::normalize::flags},
+        normalization::{NormalizationForm, is_normalized},
+    },
+};
+
+#[derive(Debug)]
+pub struct UnicodeIsNormalized;
+
+impl UnicodeIsNormalized {
+    pub(crate) fn run_impl(
+        &self,
+        _plugin: &Unicode,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let form = get_form(call)?;
+
+        match input {
+            PipelineData::Value(val, meta) => {
+                Ok(PipelineData::Value(Self::is_normalized(val, form)?, meta))
+            }
+            PipelineData::ListStream(stream, meta) => {
+                let span = stream.span();
+
+                Ok(PipelineData::ListStream(
+                    stream.map(move |val| {
+                        Self::is_normalized(val, form)
+                            .unwrap_or_else(|err| Value::error(ShellError::from(err), span))
+                    }),
+                    meta,
+                ))
+            }
+            data => Err(LabeledError::new("invalid input").with_label(
+                "Only values can be passed as input",
+                data.span().unwrap_or(Span::unknown()),
+            )),
+        }
+    }
+
+    pub(crate) fn is_normalized(
+        val: Value,
+        form: NormalizationForm,
+    ) -> Result<Value, LabeledError> {
+        let span = val.span();
+
+        match val {
+            Value::String { val, .. } => Ok(is_normalized(&val, form).into_value(span)),
+            Value::List { vals, .. } => Ok(vals
+                .into_iter()
+                .map(|val| Self::is_normalized(val, form))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_value(span)),
+            val => {
+                Err(LabeledError::new("Invalid input")
+                    .with_label("Input must be a string", val.span()))
+            }
+        }
+    }
+}
+
+impl PluginCommand for UnicodeIsNormalized {
+    type Plugin = Unicode;
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        self.run_impl(plugin, engine, call, input)
+    }
+
+    fn name(&self) -> &str {
+        constants::commands::is_normalized::NAME
+    }
+
+    fn description(&self) -> &str {
+        "Checks whether the input string is already in one of the Unicode normalization forms"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Most strings are decided by the quick check properties alone, without normalizing them. Only strings containing characters whose quick check value is Maybe are normalized and compared."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::String, Type::Bool),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::Bool)),
+                ),
+            ])
+            .named(
+                flags::FORM,
+                SyntaxShape::String,
+                "The normalization form: nfc, nfd, nfkc or nfkd. Defaults to nfc.",
+                Some('f'),
+            )
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec![
+            "unicode",
+            "normalization",
+            "nfc",
+            "quick check",
+            "predicate",
+        ]
+    }
+}
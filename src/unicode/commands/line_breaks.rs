@@ -0,0 +1,124 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, Type, Value, record,
+};
+
+use crate::{
+    Unicode,
+    unicode::{constants, line_break::line_breaks},
+};
+
+#[derive(Debug)]
+pub struct UnicodeLineBreaks;
+
+impl UnicodeLineBreaks {
+    pub(crate) fn run_impl(
+        &self,
+        _plugin: &Unicode,
+        _engine: &EngineInterface,
+        _call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        match input {
+            PipelineData::Value(val, meta) => {
+                Ok(PipelineData::Value(Self::line_breaks(val)?, meta))
+            }
+            PipelineData::ListStream(stream, meta) => {
+                let span = stream.span();
+
+                Ok(PipelineData::ListStream(
+                    stream.map(move |val| {
+                        Self::line_breaks(val)
+                            .unwrap_or_else(|err| Value::error(ShellError::from(err), span))
+                    }),
+                    meta,
+                ))
+            }
+            data => Err(LabeledError::new("invalid input").with_label(
+                "Only values can be passed as input",
+                data.span().unwrap_or(Span::unknown()),
+            )),
+        }
+    }
+
+    /// Splits the input at each line break opportunity, reporting whether
+    /// the break after each segment is mandatory or only allowed.
+    pub(crate) fn line_breaks(val: Value) -> Result<Value, LabeledError> {
+        let span = val.span();
+
+        match val {
+            Value::String { val, .. } => {
+                let mut start = 0;
+                let mut char_offset = 0;
+
+                Ok(line_breaks(&val)
+                    .into_iter()
+                    .map(|(end, opportunity)| {
+                        let segment = &val[start..end];
+                        let row = record!(
+                            "segment" => segment.into_value(span),
+                            "byte_offset" => (start as i64).into_value(span),
+                            "char_offset" => (char_offset as i64).into_value(span),
+                            "break" => opportunity.name().into_value(span),
+                        )
+                        .into_value(span);
+
+                        start = end;
+                        char_offset += segment.chars().count();
+                        row
+                    })
+                    .collect::<Vec<_>>()
+                    .into_value(span))
+            }
+            Value::List { vals, .. } => Ok(vals
+                .into_iter()
+                .map(Self::line_breaks)
+                .collect::<Result<Vec<_>, _>>()?
+                .into_value(span)),
+            val => {
+                Err(LabeledError::new("Invalid input")
+                    .with_label("Input must be a string", val.span()))
+            }
+        }
+    }
+}
+
+impl PluginCommand for UnicodeLineBreaks {
+    type Plugin = Unicode;
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        self.run_impl(plugin, engine, call, input)
+    }
+
+    fn name(&self) -> &str {
+        constants::commands::line_breaks::NAME
+    }
+
+    fn description(&self) -> &str {
+        "Lists the line break opportunities in the input string, as per the Unicode line breaking algorithm"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Break opportunities are found as per UAX #14. The input is split at each of them, and each segment is reported with the byte offset and the code point offset it starts at, and whether the break after it is mandatory, e.g. after a line feed, or only allowed. The end of the input is always a mandatory break."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name()).input_output_types(vec![
+            (Type::String, Type::Table([].into())),
+            (
+                Type::List(Box::new(Type::String)),
+                Type::List(Box::new(Type::Table([].into()))),
+            ),
+        ])
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["unicode", "line break", "wrap", "uax14", "segmentation"]
+    }
+}
@@ -0,0 +1,112 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, Type, Value, record,
+};
+
+use crate::{
+    Unicode,
+    unicode::{constants, names},
+};
+
+#[derive(Debug)]
+pub struct UnicodeLookup;
+
+impl UnicodeLookup {
+    pub(crate) fn run_impl(
+        &self,
+        _plugin: &Unicode,
+        _engine: &EngineInterface,
+        _call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        match input {
+            PipelineData::Value(val, meta) => Ok(PipelineData::Value(Self::lookup(val)?, meta)),
+            PipelineData::ListStream(stream, meta) => {
+                let span = stream.span();
+
+                Ok(PipelineData::ListStream(
+                    stream.map(move |val| {
+                        Self::lookup(val)
+                            .unwrap_or_else(|err| Value::error(ShellError::from(err), span))
+                    }),
+                    meta,
+                ))
+            }
+            data => Err(LabeledError::new("invalid input").with_label(
+                "Only values can be passed as input",
+                data.span().unwrap_or(Span::unknown()),
+            )),
+        }
+    }
+
+    pub(crate) fn lookup(val: Value) -> Result<Value, LabeledError> {
+        let span = val.span();
+
+        match val {
+            Value::String { val, .. } => {
+                let found = names::lookup(&val).ok_or_else(|| {
+                    LabeledError::new("name not found")
+                        .with_label("no character or sequence has this name", span)
+                })?;
+
+                let codepoints = found.codepoints();
+                let string = codepoints
+                    .iter()
+                    .map(|cp| char::from_u32(*cp))
+                    .collect::<Option<String>>();
+
+                Ok(record!(
+                    "name" => found.name().into_value(span),
+                    "source" => found.kind().into_value(span),
+                    "codepoints" => codepoints.into_value(span),
+                    "string" => string.into_value(span),
+                )
+                .into_value(span))
+            }
+            Value::List { vals, .. } => Ok(vals
+                .into_iter()
+                .map(Self::lookup)
+                .collect::<Result<Vec<_>, _>>()?
+                .into_value(span)),
+            val => Err(LabeledError::new("Invalid input")
+                .with_label("Input must be a character name", val.span())),
+        }
+    }
+}
+
+impl PluginCommand for UnicodeLookup {
+    type Plugin = Unicode;
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        self.run_impl(plugin, engine, call, input)
+    }
+
+    fn name(&self) -> &str {
+        constants::commands::lookup::NAME
+    }
+
+    fn description(&self) -> &str {
+        "Resolves a character name, alias, named sequence or U+XXXX codepoint to its characters"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Names are matched loosely, as per UAX44-LM2: case, whitespace, underscores and medial hyphens are ignored."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name()).input_output_types(vec![
+            (Type::String, Type::Record([].into())),
+            (Type::List(Box::new(Type::String)), Type::Table([].into())),
+        ])
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["unicode", "name", "alias", "codepoint"]
+    }
+}
@@ -1 +1,22 @@
+pub mod bidi;
+pub mod blocks;
+pub mod casefold;
 pub mod chars;
+pub mod check_version;
+pub mod compare;
+pub mod downcase;
+pub mod emoji;
+pub mod graphemes;
+pub mod is_normalized;
+pub mod line_breaks;
+pub mod lookup;
+pub mod nfkc_casefold;
+pub mod normalize;
+pub mod scripts;
+pub mod search;
+pub mod sentences;
+pub mod titlecase;
+pub mod upcase;
+pub mod width;
+pub mod words;
+pub mod wrap;
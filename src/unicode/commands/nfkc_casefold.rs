@@ -0,0 +1,119 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+
+use crate::{
+    Unicode,
+    unicode::{
+        constants::{self, commands::nfkc_casefold::flags},
+        normalization::nfkc_casefold,
+    },
+};
+
+#[derive(Debug)]
+pub struct UnicodeNfkcCasefold;
+
+impl UnicodeNfkcCasefold {
+    pub(crate) fn run_impl(
+        &self,
+        _plugin: &Unicode,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let simple = call.has_flag(flags::SIMPLE)?;
+
+        match input {
+            PipelineData::Value(val, meta) => {
+                Ok(PipelineData::Value(Self::nfkc_casefold(val, simple)?, meta))
+            }
+            PipelineData::ListStream(stream, meta) => {
+                let span = stream.span();
+
+                Ok(PipelineData::ListStream(
+                    stream.map(move |val| {
+                        Self::nfkc_casefold(val, simple)
+                            .unwrap_or_else(|err| Value::error(ShellError::from(err), span))
+                    }),
+                    meta,
+                ))
+            }
+            data => Err(LabeledError::new("invalid input").with_label(
+                "Only values can be passed as input",
+                data.span().unwrap_or(Span::unknown()),
+            )),
+        }
+    }
+
+    pub(crate) fn nfkc_casefold(val: Value, simple: bool) -> Result<Value, LabeledError> {
+        let span = val.span();
+
+        match val {
+            Value::String { val, .. } => Ok(nfkc_casefold(&val, simple).into_value(span)),
+            Value::List { vals, .. } => Ok(vals
+                .into_iter()
+                .map(|val| Self::nfkc_casefold(val, simple))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_value(span)),
+            val => {
+                Err(LabeledError::new("Invalid input")
+                    .with_label("Input must be a string", val.span()))
+            }
+        }
+    }
+}
+
+impl PluginCommand for UnicodeNfkcCasefold {
+    type Plugin = Unicode;
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        self.run_impl(plugin, engine, call, input)
+    }
+
+    fn name(&self) -> &str {
+        constants::commands::nfkc_casefold::NAME
+    }
+
+    fn description(&self) -> &str {
+        "Maps the input string to its NFKC_Casefold, for case and width insensitive lookup keys"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Each character is mapped as per the NFKC_Casefold property of `DerivedNormalizationProps.txt`, and the result is normalized to NFC. This combines NFKC normalization with full case folding, and drops default ignorable characters such as soft hyphens and zero width joiners, so that e.g. ＵＳＥＲ, User and U\u{00AD}ser all give user. With `--simple`, NFKC_Simple_Casefold is used instead, which is based on the simple case folding, so e.g. ß is kept. Strings match under `unicode compare --mode identifier` when their NFKC_Casefold is the same."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::String)),
+                ),
+            ])
+            .switch(
+                flags::SIMPLE,
+                "Use NFKC_Simple_Casefold, which is based on the simple case folding.",
+                Some('s'),
+            )
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec![
+            "unicode",
+            "nfkc",
+            "casefold",
+            "nfkc_cf",
+            "identifier",
+            "username",
+            "key",
+        ]
+    }
+}
@@ -0,0 +1,185 @@
+use std::io::{BufRead, BufReader};
+
+use encoding_rs_io::DecodeReaderBytesBuilder;
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    ByteStream, ByteStreamType, IntoValue, LabeledError, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+    shell_error::io::{self, IoError},
+};
+
+use crate::{
+    Unicode,
+    unicode::{
+        constants::{
+            self,
+            commands::normalize::{defaults, flags},
+        },
+        normalization::{NormalizationForm, Normalizer, normalize},
+    },
+};
+
+#[derive(Debug)]
+pub struct UnicodeNormalize;
+
+impl UnicodeNormalize {
+    pub(crate) fn run_impl(
+        &self,
+        _plugin: &Unicode,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let form = get_form(call)?;
+
+        match input {
+            PipelineData::Value(val, meta) => {
+                Ok(PipelineData::Value(Self::normalize(val, form)?, meta))
+            }
+            PipelineData::ListStream(stream, meta) => {
+                let span = stream.span();
+
+                Ok(PipelineData::ListStream(
+                    stream.map(move |val| {
+                        Self::normalize(val, form)
+                            .unwrap_or_else(|err| Value::error(ShellError::from(err), span))
+                    }),
+                    meta,
+                ))
+            }
+            PipelineData::ByteStream(stream, meta) => {
+                let span = stream.span();
+                let reader = match stream.reader() {
+                    None => return Ok(PipelineData::empty()),
+                    Some(r) => r,
+                };
+
+                let mut decoder = BufReader::new(
+                    DecodeReaderBytesBuilder::new()
+                        .encoding(Some(encoding_rs::UTF_8))
+                        .build(reader),
+                );
+                let mut normalizer = Some(Normalizer::new(form));
+
+                let out_stream = ByteStream::from_fn(
+                    span,
+                    engine.signals().clone(),
+                    ByteStreamType::String,
+                    move |out| {
+                        let Some(current) = normalizer.as_mut() else {
+                            return Ok(false);
+                        };
+
+                        let buf = decoder.fill_buf().map_err(|err| {
+                            ShellError::from(IoError::new(io::ErrorKind::from(err), span, None))
+                        })?;
+
+                        if buf.is_empty() {
+                            out.extend(normalizer.take().unwrap().finish().into_bytes());
+                            return Ok(true);
+                        }
+
+                        let read_bytes = buf.len();
+                        // the decoder only ever gives back whole UTF-8 characters
+                        let chunk = unsafe { std::str::from_utf8_unchecked(buf) };
+                        out.extend(current.push(chunk).into_bytes());
+                        decoder.consume(read_bytes);
+
+                        Ok(true)
+                    },
+                );
+
+                Ok(PipelineData::ByteStream(out_stream, meta))
+            }
+            data => Err(LabeledError::new("invalid input").with_label(
+                "Only values can be passed as input",
+                data.span().unwrap_or(Span::unknown()),
+            )),
+        }
+    }
+
+    pub(crate) fn normalize(val: Value, form: NormalizationForm) -> Result<Value, LabeledError> {
+        let span = val.span();
+
+        match val {
+            Value::String { val, .. } => Ok(normalize(&val, form).into_value(span)),
+            Value::Binary { val, .. } => {
+                Ok(normalize(&String::from_utf8_lossy(&val), form).into_value(span))
+            }
+            Value::List { vals, .. } => Ok(vals
+                .into_iter()
+                .map(|val| Self::normalize(val, form))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_value(span)),
+            val => {
+                Err(LabeledError::new("Invalid input")
+                    .with_label("Input must be a string", val.span()))
+            }
+        }
+    }
+}
+
+/// Gets the normalization form from the `--form` flag.
+pub(crate) fn get_form(call: &EvaluatedCall) -> Result<NormalizationForm, LabeledError> {
+    let form = call
+        .get_flag_value(flags::FORM)
+        .unwrap_or(Value::string(defaults::FORM, Span::unknown()));
+    let form_span = form.span();
+
+    NormalizationForm::from_name(&form.into_string()?).ok_or_else(|| {
+        LabeledError::new("invalid normalization form")
+            .with_label("no such normalization form", form_span)
+            .with_help(format!(
+                "expected one of {}",
+                NormalizationForm::NAMES.join(", ")
+            ))
+    })
+}
+
+impl PluginCommand for UnicodeNormalize {
+    type Plugin = Unicode;
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        self.run_impl(plugin, engine, call, input)
+    }
+
+    fn name(&self) -> &str {
+        constants::commands::normalize::NAME
+    }
+
+    fn description(&self) -> &str {
+        "Normalizes the input string to one of the Unicode normalization forms"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Implements UAX #15. NFD and NFKD fully decompose the text, using canonical or compatibility decompositions respectively, and put combining marks in canonical order. NFC and NFKC then recompose it. Byte streams are decoded as UTF-8 and normalized as they stream in."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (Type::Binary, Type::String),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::String)),
+                ),
+            ])
+            .named(
+                flags::FORM,
+                SyntaxShape::String,
+                "The normalization form: nfc, nfd, nfkc or nfkd. Defaults to nfc.",
+                Some('f'),
+            )
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["unicode", "normalization", "nfc", "nfd", "nfkc", "nfkd"]
+    }
+}
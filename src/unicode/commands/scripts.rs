@@ -0,0 +1,134 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_plugin_unicode_ucd::codegen::{
+    property_value_aliases::property_value_alias,
+    scripts::{script_abbreviation_of, script_extensions_of},
+};
+use nu_protocol::{
+    IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, Type, Value, record,
+};
+
+use crate::{
+    Unicode,
+    unicode::constants::{self, commands::scripts::flags},
+};
+
+#[derive(Debug)]
+pub struct UnicodeScripts;
+
+impl UnicodeScripts {
+    pub(crate) fn run_impl(
+        &self,
+        _plugin: &Unicode,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let extensions = call.has_flag(flags::EXTENSIONS)?;
+
+        match input {
+            PipelineData::Value(val, meta) => {
+                Ok(PipelineData::Value(Self::scripts(val, extensions)?, meta))
+            }
+            PipelineData::ListStream(stream, meta) => {
+                let span = stream.span();
+
+                Ok(PipelineData::ListStream(
+                    stream.map(move |val| {
+                        Self::scripts(val, extensions)
+                            .unwrap_or_else(|err| Value::error(ShellError::from(err), span))
+                    }),
+                    meta,
+                ))
+            }
+            data => Err(LabeledError::new("invalid input").with_label(
+                "Only values can be passed as input",
+                data.span().unwrap_or(Span::unknown()),
+            )),
+        }
+    }
+
+    pub(crate) fn scripts(val: Value, extensions: bool) -> Result<Value, LabeledError> {
+        let span = val.span();
+
+        match val {
+            Value::String { val, .. } => {
+                // (abbreviated name, number of chars), in order of first use
+                let mut counts: Vec<(&'static str, i64)> = Vec::new();
+
+                for ch in val.chars() {
+                    let scripts = if extensions {
+                        script_extensions_of(ch as u32)
+                    } else {
+                        &[script_abbreviation_of(ch as u32)][..]
+                    };
+
+                    for script in scripts {
+                        match counts.iter_mut().find(|(seen, _)| seen == script) {
+                            Some((_, count)) => *count += 1,
+                            None => counts.push((script, 1)),
+                        }
+                    }
+                }
+
+                Ok(counts
+                    .into_iter()
+                    .map(|(script, count)| {
+                        let long =
+                            property_value_alias("sc", script).map_or(script, |alias| alias.long);
+
+                        record!(
+                            "script" => long.into_value(span),
+                            "short" => script.into_value(span),
+                            "count" => count.into_value(span),
+                        )
+                        .into_value(span)
+                    })
+                    .collect::<Vec<_>>()
+                    .into_value(span))
+            }
+            Value::List { vals, .. } => Ok(vals
+                .into_iter()
+                .map(|val| Self::scripts(val, extensions))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_value(span)),
+            val => {
+                Err(LabeledError::new("Invalid input")
+                    .with_label("Input must be a string", val.span()))
+            }
+        }
+    }
+}
+
+impl PluginCommand for UnicodeScripts {
+    type Plugin = Unicode;
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        self.run_impl(plugin, engine, call, input)
+    }
+
+    fn name(&self) -> &str {
+        constants::commands::scripts::NAME
+    }
+
+    fn description(&self) -> &str {
+        "Summarizes the scripts used in the input string, with the number of characters in each"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name()).input_output_types(vec![
+            (Type::String, Type::Table([].into())),
+            (Type::List(Box::new(Type::String)), Type::List(Box::new(Type::Table([].into())))),
+        ])
+        .switch(flags::EXTENSIONS, "Count each character towards every script in its Script_Extensions property, rather than just its Script property.", Some('x'))
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["unicode", "script", "writing system", "language"]
+    }
+}
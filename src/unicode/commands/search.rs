@@ -0,0 +1,277 @@
+use std::sync::OnceLock;
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_plugin_unicode_ucd::{
+    codegen::{
+        blocks::BLOCKS,
+        name_aliases::NAME_ALIASES,
+        names::{NAME_PATTERNS, name_of},
+        names_list::NAMES_LIST,
+        scripts::SCRIPTS,
+        unicode_data::{UNICODE_DATA, general_category_of},
+    },
+    hangul,
+};
+use nu_protocol::{
+    IntoValue, LabeledError, ListStream, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+use crate::{
+    Unicode,
+    unicode::{
+        commands::{
+            chars::{self, get_unicode_values},
+            search::config::Config,
+        },
+        constants::{self, commands::search::flags},
+        properties::loose_value_key,
+    },
+};
+
+pub mod config;
+
+/// How much a match in each part of the corpus counts towards the score.
+const NAME_WEIGHT: f64 = 1.0;
+const ALIAS_WEIGHT: f64 = 0.9;
+const INFORMAL_ALIAS_WEIGHT: f64 = 0.7;
+const CROSS_REFERENCE_WEIGHT: f64 = 0.4;
+
+#[derive(Debug)]
+pub struct UnicodeSearch;
+
+impl UnicodeSearch {
+    pub(crate) fn run_impl(
+        &self,
+        _plugin: &Unicode,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let config = Config::try_from(call)?;
+        let query = Query::new(&config);
+        let span = call.head;
+        let chars_config = chars::config::Config::default();
+
+        let results = searchable_codepoints().iter().filter_map(move |&cp| {
+            if !matches_filters(cp, &config) {
+                return None;
+            }
+
+            let score = query.score(cp);
+
+            if score == 0 {
+                return None;
+            }
+
+            let record =
+                get_unicode_values(cp, &chars_config, span).and_then(|val| Ok(val.into_record()?));
+
+            Some(match record {
+                Ok(mut record) => {
+                    record.push("score", score.into_value(span));
+                    record.into_value(span)
+                }
+                Err(err) => Value::error(ShellError::from(err), span),
+            })
+        });
+
+        Ok(PipelineData::ListStream(
+            ListStream::new(results, span, engine.signals().clone()),
+            None,
+        ))
+    }
+}
+
+/// All codepoints that have a name or alias, in order.
+fn searchable_codepoints() -> &'static [u32] {
+    static CODEPOINTS: OnceLock<Vec<u32>> = OnceLock::new();
+
+    CODEPOINTS.get_or_init(|| {
+        let mut cps: Vec<u32> = UNICODE_DATA
+            .keys()
+            .copied()
+            .chain(hangul::S_BASE..hangul::S_BASE + hangul::S_COUNT)
+            .chain(
+                NAME_PATTERNS
+                    .iter()
+                    .flat_map(|(start, end, _)| *start..=*end),
+            )
+            .collect();
+
+        cps.sort_unstable();
+        cps.dedup();
+        cps
+    })
+}
+
+fn matches_filters(cp: u32, config: &Config) -> bool {
+    let matches_table = |filter: &Option<String>, value: Option<&&str>| match filter {
+        None => true,
+        Some(filter) => value.is_some_and(|value| loose_value_key(value) == *filter),
+    };
+
+    if !matches_table(&config.block, BLOCKS.get(cp)) {
+        return false;
+    }
+
+    if !matches_table(&config.script, SCRIPTS.get(cp)) {
+        return false;
+    }
+
+    config
+        .category
+        .is_none_or(|category| in_general_category(general_category_of(cp), category))
+}
+
+/// Whether a general category is the given one, or in the given group of
+/// general categories, e.g. L for all letters.
+fn in_general_category(general_category: &str, category: &str) -> bool {
+    match category {
+        "LC" => matches!(general_category, "Lu" | "Ll" | "Lt"),
+        group if group.len() == 1 => general_category.starts_with(group),
+        category => general_category == category,
+    }
+}
+
+struct Query {
+    /// The whole query, uppercased and with its whitespace collapsed.
+    text: String,
+    words: Vec<String>,
+    /// The query's characters, for fuzzy matching. Empty if fuzzy matching
+    /// is disabled.
+    fuzzy: Vec<char>,
+}
+
+impl Query {
+    fn new(config: &Config) -> Self {
+        let words: Vec<String> = config
+            .query
+            .split_whitespace()
+            .map(str::to_uppercase)
+            .collect();
+
+        let fuzzy = if config.fuzzy {
+            words.iter().flat_map(|word| word.chars()).collect()
+        } else {
+            Vec::new()
+        };
+
+        Query {
+            text: words.join(" "),
+            words,
+            fuzzy,
+        }
+    }
+
+    /// Scores the codepoint against the query, from 0 (no match) to 100.
+    fn score(&self, cp: u32) -> i64 {
+        let mut best = 0.0_f64;
+        let mut consider = |text: &str, weight: f64| {
+            best = best.max(weight * self.score_text(text));
+        };
+
+        if let Some(name) = name_of(cp) {
+            consider(&name, NAME_WEIGHT);
+        }
+
+        if let Some(aliases) = NAME_ALIASES.get(&cp) {
+            for alias in aliases.iter() {
+                consider(alias.alias, ALIAS_WEIGHT);
+            }
+        }
+
+        if let Some(entry) = NAMES_LIST.get(&cp) {
+            for alias in entry.informal_aliases {
+                consider(alias, INFORMAL_ALIAS_WEIGHT);
+            }
+
+            for xref in entry.cross_references {
+                if let Some(name) = name_of(*xref) {
+                    consider(&name, CROSS_REFERENCE_WEIGHT);
+                }
+            }
+        }
+
+        (best * 100.0).round() as i64
+    }
+
+    fn score_text(&self, text: &str) -> f64 {
+        let text = text.to_uppercase();
+
+        if text == self.text {
+            return 1.0;
+        }
+
+        let words: Vec<&str> = text.split([' ', '-']).collect();
+
+        if self.words.iter().all(|word| words.contains(&word.as_str())) {
+            return if text.starts_with(&self.text) {
+                0.9
+            } else {
+                0.8
+            };
+        }
+
+        if self.words.iter().all(|word| text.contains(word.as_str())) {
+            return 0.6;
+        }
+
+        if !self.fuzzy.is_empty() && is_subsequence(&self.fuzzy, &text) {
+            // favor matches where the query makes up more of the text
+            return 0.4 * self.fuzzy.len() as f64 / text.chars().count() as f64;
+        }
+
+        0.0
+    }
+}
+
+fn is_subsequence(needle: &[char], haystack: &str) -> bool {
+    let mut needle = needle.iter().peekable();
+
+    for ch in haystack.chars() {
+        needle.next_if(|next| **next == ch);
+    }
+
+    needle.peek().is_none()
+}
+
+impl PluginCommand for UnicodeSearch {
+    type Plugin = Unicode;
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        self.run_impl(plugin, engine, call, input)
+    }
+
+    fn name(&self) -> &str {
+        constants::commands::search::NAME
+    }
+
+    fn description(&self) -> &str {
+        "Searches character names, aliases and Names List annotations, and returns metadata about each matching code point"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Every word of the query must appear in the name, one of the aliases, one of the informal aliases from the Unicode Names List, or the name of a cross-referenced character. With --fuzzy, the query also matches text which contains its characters in order, though not necessarily next to each other, e.g. 'ltrarw' matches LEFTWARDS ARROW, with a lower score the more other characters there are. Each result has a score from 0 to 100 saying how well it matched."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Table([].into()))])
+            .required("query", SyntaxShape::String, "The text to search for.")
+            .named(flags::BLOCK, SyntaxShape::String, "Only return code points in this block, given by any of its aliases.", Some('b'))
+            .named(flags::SCRIPT, SyntaxShape::String, "Only return code points in this script, given by any of its aliases.", Some('s'))
+            .named(flags::CATEGORY, SyntaxShape::String, "Only return code points in this general category, given by any of its aliases, e.g. Lu or Uppercase_Letter, or L for all letters.", Some('c'))
+            .switch(flags::FUZZY, "Also match names which contain the characters of the query in order, though not necessarily next to each other.", Some('f'))
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["unicode", "find", "name", "alias"]
+    }
+}
@@ -0,0 +1,97 @@
+use nu_plugin::EvaluatedCall;
+use nu_plugin_unicode_ucd::{
+    codegen::{blocks::BLOCKS, property_value_aliases::PROPERTY_VALUE_ALIASES, scripts::SCRIPTS},
+    types::ranges::RangeTable,
+};
+use nu_protocol::{LabeledError, Spanned};
+
+use crate::unicode::{
+    constants::commands::search::flags,
+    properties::{loose_value_key, resolve_value_key},
+};
+
+#[derive(Clone)]
+pub struct Config {
+    pub query: String,
+    pub fuzzy: bool,
+    /// The loose matching key of the block to restrict results to.
+    pub block: Option<String>,
+    /// The loose matching key of the script to restrict results to.
+    pub script: Option<String>,
+    /// The abbreviation of the general category, or group of general
+    /// categories, to restrict results to.
+    pub category: Option<&'static str>,
+}
+
+impl TryFrom<&EvaluatedCall> for Config {
+    type Error = LabeledError;
+
+    fn try_from(call: &EvaluatedCall) -> Result<Self, Self::Error> {
+        let query: Spanned<String> = call.req(0)?;
+
+        if query.item.trim().is_empty() {
+            return Err(LabeledError::new("empty query")
+                .with_label("search query must not be empty", query.span));
+        }
+
+        let fuzzy = call.has_flag(flags::FUZZY)?;
+        let block = property_filter(call, flags::BLOCK, "blk", &BLOCKS)?;
+        let script = property_filter(call, flags::SCRIPT, "sc", &SCRIPTS)?;
+
+        let category = general_category_filter(call)?;
+
+        Ok(Config {
+            query: query.item,
+            fuzzy,
+            block,
+            script,
+            category,
+        })
+    }
+}
+
+/// Reads the value of a filter flag, which may be any alias of a value of
+/// `property`, and checks that it names one of the values in the given table.
+fn property_filter(
+    call: &EvaluatedCall,
+    flag: &str,
+    property: &str,
+    table: &RangeTable<&str>,
+) -> Result<Option<String>, LabeledError> {
+    let Some(value) = call.get_flag::<Spanned<String>>(flag)? else {
+        return Ok(None);
+    };
+
+    let key = resolve_value_key(property, &value.item);
+
+    if !table
+        .iter()
+        .any(|(_, _, name)| loose_value_key(name) == key)
+    {
+        return Err(LabeledError::new(format!("unknown {flag}"))
+            .with_label(format!("no {flag} has this name"), value.span));
+    }
+
+    Ok(Some(key))
+}
+
+/// Reads the value of the general category flag, which may be any alias of a
+/// general category, such as Lu or Uppercase_Letter, or of a group of them,
+/// such as L or Letter, and returns its abbreviation.
+fn general_category_filter(call: &EvaluatedCall) -> Result<Option<&'static str>, LabeledError> {
+    let Some(value) = call.get_flag::<Spanned<String>>(flags::CATEGORY)? else {
+        return Ok(None);
+    };
+
+    let key = resolve_value_key("gc", &value.item);
+
+    PROPERTY_VALUE_ALIASES
+        .iter()
+        .find(|alias| alias.property == "gc" && loose_value_key(alias.long) == key)
+        .map(|alias| Some(alias.abbreviation))
+        .ok_or_else(|| {
+            LabeledError::new("invalid general category")
+                .with_label("no general category has this name", value.span)
+                .with_help("expected a general category like Lu or Uppercase_Letter, or a group of them like L or Letter")
+        })
+}
@@ -0,0 +1,200 @@
+use std::io::{BufRead, BufReader};
+
+use encoding_rs_io::DecodeReaderBytesBuilder;
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    IntoValue, LabeledError, ListStream, PipelineData, ShellError, Signature, Span, Type, Value,
+    record,
+    shell_error::io::{self, IoError},
+};
+
+use crate::{
+    Unicode,
+    unicode::{
+        constants,
+        segmentation::sentence::{SentenceSplitter, sentences},
+    },
+};
+
+#[derive(Debug)]
+pub struct UnicodeSentences;
+
+impl UnicodeSentences {
+    pub(crate) fn run_impl(
+        &self,
+        _plugin: &Unicode,
+        engine: &EngineInterface,
+        _call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        match input {
+            PipelineData::Value(val, meta) => Ok(PipelineData::Value(Self::sentences(val)?, meta)),
+            PipelineData::ListStream(stream, meta) => {
+                let span = stream.span();
+
+                Ok(PipelineData::ListStream(
+                    stream.map(move |val| {
+                        Self::sentences(val)
+                            .unwrap_or_else(|err| Value::error(ShellError::from(err), span))
+                    }),
+                    meta,
+                ))
+            }
+            PipelineData::ByteStream(stream, meta) => {
+                let span = stream.span();
+                let reader = match stream.reader() {
+                    None => return Ok(PipelineData::empty()),
+                    Some(r) => r,
+                };
+
+                let mut decoder = BufReader::new(
+                    DecodeReaderBytesBuilder::new()
+                        .encoding(Some(encoding_rs::UTF_8))
+                        .build(reader),
+                );
+                // the sentences at the end of each chunk may carry on into
+                // the next chunk, so they are held back until that is read
+                let mut splitter = SentenceSplitter::new();
+                let mut byte_offset = 0;
+                let mut char_offset = 0;
+
+                let vals = std::iter::from_fn(move || {
+                    let buf = match decoder.fill_buf() {
+                        Ok(bytes) => bytes,
+                        Err(err) => {
+                            return Some(vec![Value::error(
+                                ShellError::from(IoError::new(
+                                    io::ErrorKind::from(err),
+                                    span,
+                                    None,
+                                )),
+                                span,
+                            )]);
+                        }
+                    };
+
+                    let read_bytes = buf.len();
+
+                    let text = if read_bytes == 0 {
+                        let rest = splitter.finish();
+
+                        if rest.is_empty() {
+                            return None;
+                        }
+
+                        rest
+                    } else {
+                        // the decoder only ever gives back whole UTF-8
+                        // characters
+                        let text = splitter.push(unsafe { std::str::from_utf8_unchecked(buf) });
+                        decoder.consume(read_bytes);
+                        text
+                    };
+
+                    let vals =
+                        sentence_records(&text, byte_offset, char_offset, span).collect::<Vec<_>>();
+                    byte_offset += text.len();
+                    char_offset += text.chars().count();
+
+                    Some(vals)
+                })
+                .flatten();
+
+                Ok(PipelineData::ListStream(
+                    ListStream::new(vals, span, engine.signals().clone()),
+                    meta,
+                ))
+            }
+            data => Err(LabeledError::new("invalid input").with_label(
+                "Only values can be passed as input",
+                data.span().unwrap_or(Span::unknown()),
+            )),
+        }
+    }
+
+    pub(crate) fn sentences(val: Value) -> Result<Value, LabeledError> {
+        let span = val.span();
+
+        match val {
+            Value::String { val, .. } => Ok(sentence_records(&val, 0, 0, span)
+                .collect::<Vec<_>>()
+                .into_value(span)),
+            Value::Binary { val, .. } => {
+                Ok(sentence_records(&String::from_utf8_lossy(&val), 0, 0, span)
+                    .collect::<Vec<_>>()
+                    .into_value(span))
+            }
+            Value::List { vals, .. } => Ok(vals
+                .into_iter()
+                .map(Self::sentences)
+                .collect::<Result<Vec<_>, _>>()?
+                .into_value(span)),
+            val => {
+                Err(LabeledError::new("Invalid input")
+                    .with_label("Input must be a string", val.span()))
+            }
+        }
+    }
+}
+
+/// Splits the text into a record per sentence, with offsets counted from
+/// the given ones, for text which is part of a longer stream.
+fn sentence_records(
+    text: &str,
+    byte_offset: usize,
+    mut char_offset: usize,
+    span: Span,
+) -> impl Iterator<Item = Value> + '_ {
+    sentences(text).map(move |(offset, sentence)| {
+        let val = record!(
+            "sentence" => sentence.into_value(span),
+            "byte_offset" => ((byte_offset + offset) as i64).into_value(span),
+            "char_offset" => (char_offset as i64).into_value(span),
+        )
+        .into_value(span);
+
+        char_offset += sentence.chars().count();
+        val
+    })
+}
+
+impl PluginCommand for UnicodeSentences {
+    type Plugin = Unicode;
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        self.run_impl(plugin, engine, call, input)
+    }
+
+    fn name(&self) -> &str {
+        constants::commands::sentences::NAME
+    }
+
+    fn description(&self) -> &str {
+        "Splits the input string into sentences, as per the default sentence boundaries of Unicode"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Boundaries are found as per UAX #29, so that e.g. abbreviations followed by a lowercase word and decimal numbers don't end a sentence. The whitespace and line breaks after a sentence are part of it. Byte streams are decoded as UTF-8 and split as they are read, so large documents don't need to be collected first. Each sentence is reported with the byte offset and the code point offset it starts at."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name()).input_output_types(vec![
+            (Type::String, Type::Table([].into())),
+            (Type::Binary, Type::Table([].into())),
+            (
+                Type::List(Box::new(Type::String)),
+                Type::List(Box::new(Type::Table([].into()))),
+            ),
+        ])
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["unicode", "sentence", "segmentation", "split"]
+    }
+}
@@ -0,0 +1,128 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use crate::{
+    Unicode,
+    unicode::{
+        casing::{language_of, to_uppercase},
+        constants::{self, commands::upcase::flags},
+    },
+};
+
+#[derive(Debug)]
+pub struct UnicodeUpcase;
+
+impl UnicodeUpcase {
+    pub(crate) fn run_impl(
+        &self,
+        _plugin: &Unicode,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let language = get_language(call)?;
+
+        match input {
+            PipelineData::Value(val, meta) => {
+                Ok(PipelineData::Value(Self::upcase(val, &language)?, meta))
+            }
+            PipelineData::ListStream(stream, meta) => {
+                let span = stream.span();
+
+                Ok(PipelineData::ListStream(
+                    stream.map(move |val| {
+                        Self::upcase(val, &language)
+                            .unwrap_or_else(|err| Value::error(ShellError::from(err), span))
+                    }),
+                    meta,
+                ))
+            }
+            data => Err(LabeledError::new("invalid input").with_label(
+                "Only values can be passed as input",
+                data.span().unwrap_or(Span::unknown()),
+            )),
+        }
+    }
+
+    pub(crate) fn upcase(val: Value, language: &str) -> Result<Value, LabeledError> {
+        let span = val.span();
+
+        match val {
+            Value::String { val, .. } => Ok(to_uppercase(&val, language).into_value(span)),
+            Value::List { vals, .. } => Ok(vals
+                .into_iter()
+                .map(|val| Self::upcase(val, language))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_value(span)),
+            val => {
+                Err(LabeledError::new("Invalid input")
+                    .with_label("Input must be a string", val.span()))
+            }
+        }
+    }
+}
+
+/// Gets the language whose case mapping rules apply from the `--locale`
+/// flag, or an empty string for the language-independent rules.
+pub(crate) fn get_language(call: &EvaluatedCall) -> Result<String, LabeledError> {
+    Ok(call
+        .get_flag::<String>(flags::LOCALE)?
+        .map(|locale| language_of(&locale))
+        .unwrap_or_default())
+}
+
+impl PluginCommand for UnicodeUpcase {
+    type Plugin = Unicode;
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        self.run_impl(plugin, engine, call, input)
+    }
+
+    fn name(&self) -> &str {
+        constants::commands::upcase::NAME
+    }
+
+    fn description(&self) -> &str {
+        "Maps the input string to uppercase"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Unlike `str upcase`, the full case mappings of `SpecialCasing.txt` are applied, so that e.g. ß becomes SS and ŉ becomes ʼN. With `--locale`, the rules for that language are applied too: in Turkish and Azerbaijani, i becomes İ, and in Lithuanian, a combining dot above after a soft-dotted letter is removed."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::String)),
+                ),
+            ])
+            .named(
+                flags::LOCALE,
+                SyntaxShape::String,
+                "The language whose case mapping rules apply, e.g. tr, az or lt. Other languages use the default rules.",
+                Some('l'),
+            )
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec![
+            "unicode",
+            "upcase",
+            "uppercase",
+            "case",
+            "capital",
+            "locale",
+        ]
+    }
+}
@@ -0,0 +1,113 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+
+use crate::{
+    Unicode,
+    unicode::{
+        constants::{self, commands::width::flags},
+        width::str_width,
+    },
+};
+
+#[derive(Debug)]
+pub struct UnicodeWidth;
+
+impl UnicodeWidth {
+    pub(crate) fn run_impl(
+        &self,
+        _plugin: &Unicode,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let ambiguous_wide = call.has_flag(flags::AMBIGUOUS_WIDE)?;
+
+        match input {
+            PipelineData::Value(val, meta) => {
+                Ok(PipelineData::Value(Self::width(val, ambiguous_wide)?, meta))
+            }
+            PipelineData::ListStream(stream, meta) => {
+                let span = stream.span();
+
+                Ok(PipelineData::ListStream(
+                    stream.map(move |val| {
+                        Self::width(val, ambiguous_wide)
+                            .unwrap_or_else(|err| Value::error(ShellError::from(err), span))
+                    }),
+                    meta,
+                ))
+            }
+            data => Err(LabeledError::new("invalid input").with_label(
+                "Only values can be passed as input",
+                data.span().unwrap_or(Span::unknown()),
+            )),
+        }
+    }
+
+    pub(crate) fn width(val: Value, ambiguous_wide: bool) -> Result<Value, LabeledError> {
+        let span = val.span();
+
+        match val {
+            Value::String { val, .. } => {
+                Ok((str_width(&val, ambiguous_wide) as i64).into_value(span))
+            }
+            Value::List { vals, .. } => Ok(vals
+                .into_iter()
+                .map(|val| Self::width(val, ambiguous_wide))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_value(span)),
+            val => {
+                Err(LabeledError::new("Invalid input")
+                    .with_label("Input must be a string", val.span()))
+            }
+        }
+    }
+}
+
+impl PluginCommand for UnicodeWidth {
+    type Plugin = Unicode;
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        self.run_impl(plugin, engine, call, input)
+    }
+
+    fn name(&self) -> &str {
+        constants::commands::width::NAME
+    }
+
+    fn description(&self) -> &str {
+        "Computes the number of terminal columns the input string takes up when displayed"
+    }
+
+    fn extra_description(&self) -> &str {
+        "The width is computed per extended grapheme cluster, from the East_Asian_Width property. Emoji presentation sequences and flags are two columns wide, and combining marks, format characters and controls take up no columns."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::String, Type::Int),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::Int)),
+                ),
+            ])
+            .switch(
+                flags::AMBIGUOUS_WIDE,
+                "Treat characters of ambiguous width as wide, as terminals in CJK locales do.",
+                Some('a'),
+            )
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["unicode", "width", "columns", "east asian", "wcwidth"]
+    }
+}
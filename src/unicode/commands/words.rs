@@ -0,0 +1,130 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, Type, Value, record,
+};
+
+use crate::{
+    Unicode,
+    unicode::{
+        constants::{self, commands::words::flags},
+        segmentation::word::{is_word, words},
+    },
+};
+
+#[derive(Debug)]
+pub struct UnicodeWords;
+
+impl UnicodeWords {
+    pub(crate) fn run_impl(
+        &self,
+        _plugin: &Unicode,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let words_only = call.has_flag(flags::WORDS_ONLY)?;
+
+        match input {
+            PipelineData::Value(val, meta) => {
+                Ok(PipelineData::Value(Self::words(val, words_only)?, meta))
+            }
+            PipelineData::ListStream(stream, meta) => {
+                let span = stream.span();
+
+                Ok(PipelineData::ListStream(
+                    stream.map(move |val| {
+                        Self::words(val, words_only)
+                            .unwrap_or_else(|err| Value::error(ShellError::from(err), span))
+                    }),
+                    meta,
+                ))
+            }
+            data => Err(LabeledError::new("invalid input").with_label(
+                "Only values can be passed as input",
+                data.span().unwrap_or(Span::unknown()),
+            )),
+        }
+    }
+
+    /// Splits the input into word segments, optionally dropping the ones
+    /// which aren't words.
+    pub(crate) fn words(val: Value, words_only: bool) -> Result<Value, LabeledError> {
+        let span = val.span();
+
+        match val {
+            Value::String { val, .. } => {
+                let mut char_offset = 0;
+
+                Ok(words(&val)
+                    .filter_map(|(byte_offset, segment)| {
+                        let val = record!(
+                            "word" => segment.into_value(span),
+                            "byte_offset" => (byte_offset as i64).into_value(span),
+                            "char_offset" => (char_offset as i64).into_value(span),
+                        )
+                        .into_value(span);
+
+                        char_offset += segment.chars().count();
+                        (!words_only || is_word(segment)).then_some(val)
+                    })
+                    .collect::<Vec<_>>()
+                    .into_value(span))
+            }
+            Value::List { vals, .. } => Ok(vals
+                .into_iter()
+                .map(|val| Self::words(val, words_only))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_value(span)),
+            val => {
+                Err(LabeledError::new("Invalid input")
+                    .with_label("Input must be a string", val.span()))
+            }
+        }
+    }
+}
+
+impl PluginCommand for UnicodeWords {
+    type Plugin = Unicode;
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        self.run_impl(plugin, engine, call, input)
+    }
+
+    fn name(&self) -> &str {
+        constants::commands::words::NAME
+    }
+
+    fn description(&self) -> &str {
+        "Splits the input string into words, as per the default word boundaries of Unicode"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Boundaries are found as per UAX #29, so that e.g. contractions, decimal numbers and emoji sequences are kept whole. By default, the whitespace and punctuation between words are returned as segments too. Each segment is reported with the byte offset and the code point offset it starts at."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::String, Type::Table([].into())),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::Table([].into()))),
+                ),
+            ])
+            .switch(
+                flags::WORDS_ONLY,
+                "Only return segments which contain letters or numbers, dropping whitespace, punctuation and symbols.",
+                Some('w'),
+            )
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["unicode", "word", "tokenize", "segmentation", "split"]
+    }
+}
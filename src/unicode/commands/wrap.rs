@@ -0,0 +1,139 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use crate::{
+    Unicode,
+    unicode::{
+        constants::{self, commands::wrap::flags},
+        line_break::wrap,
+    },
+};
+
+#[derive(Debug)]
+pub struct UnicodeWrap;
+
+impl UnicodeWrap {
+    pub(crate) fn run_impl(
+        &self,
+        _plugin: &Unicode,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let width = get_width(call)?;
+        let ambiguous_wide = call.has_flag(flags::AMBIGUOUS_WIDE)?;
+
+        match input {
+            PipelineData::Value(val, meta) => Ok(PipelineData::Value(
+                Self::wrap(val, width, ambiguous_wide)?,
+                meta,
+            )),
+            PipelineData::ListStream(stream, meta) => {
+                let span = stream.span();
+
+                Ok(PipelineData::ListStream(
+                    stream.map(move |val| {
+                        Self::wrap(val, width, ambiguous_wide)
+                            .unwrap_or_else(|err| Value::error(ShellError::from(err), span))
+                    }),
+                    meta,
+                ))
+            }
+            data => Err(LabeledError::new("invalid input").with_label(
+                "Only values can be passed as input",
+                data.span().unwrap_or(Span::unknown()),
+            )),
+        }
+    }
+
+    pub(crate) fn wrap(
+        val: Value,
+        width: usize,
+        ambiguous_wide: bool,
+    ) -> Result<Value, LabeledError> {
+        let span = val.span();
+
+        match val {
+            Value::String { val, .. } => Ok(wrap(&val, width, ambiguous_wide).into_value(span)),
+            Value::List { vals, .. } => Ok(vals
+                .into_iter()
+                .map(|val| Self::wrap(val, width, ambiguous_wide))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_value(span)),
+            val => {
+                Err(LabeledError::new("Invalid input")
+                    .with_label("Input must be a string", val.span()))
+            }
+        }
+    }
+}
+
+/// Gets the line width from the `--width` flag, which must be positive.
+fn get_width(call: &EvaluatedCall) -> Result<usize, LabeledError> {
+    let width = call.get_flag_value(flags::WIDTH).ok_or_else(|| {
+        LabeledError::new("missing width").with_label("the --width flag is required", call.head)
+    })?;
+    let width_span = width.span();
+
+    usize::try_from(width.as_int()?)
+        .ok()
+        .filter(|width| *width > 0)
+        .ok_or_else(|| {
+            LabeledError::new("invalid width")
+                .with_label("the width must be a positive number of columns", width_span)
+        })
+}
+
+impl PluginCommand for UnicodeWrap {
+    type Plugin = Unicode;
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        self.run_impl(plugin, engine, call, input)
+    }
+
+    fn name(&self) -> &str {
+        constants::commands::wrap::NAME
+    }
+
+    fn description(&self) -> &str {
+        "Wraps the input string to lines of at most the given number of terminal columns"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Lines are only broken where the Unicode line breaking algorithm (UAX #14) allows it, so that e.g. CJK text may break between any two ideographs but not before closing punctuation, and emoji sequences are never split. Widths are measured per extended grapheme cluster, as with `unicode width`. Spaces at the end of a wrapped line are dropped, existing line breaks are kept, and words too wide for a line of their own are split between grapheme clusters."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::String)),
+                ),
+            ])
+            .required_named(
+                flags::WIDTH,
+                SyntaxShape::Int,
+                "The maximum number of terminal columns per line.",
+                Some('w'),
+            )
+            .switch(
+                flags::AMBIGUOUS_WIDE,
+                "Treat characters of ambiguous width as wide, as terminals in CJK locales do.",
+                Some('a'),
+            )
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["unicode", "wrap", "line break", "width", "fill", "cjk"]
+    }
+}
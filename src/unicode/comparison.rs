@@ -0,0 +1,130 @@
+use crate::unicode::{
+    casing::casefold,
+    normalization::{NormalizationForm, nfkc_casefold, normalize},
+    segmentation::grapheme::graphemes,
+};
+
+/// The equivalences under which two strings can be compared, from the
+/// strictest to the loosest.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Equivalence {
+    /// The strings have the same codepoints.
+    Exact,
+    /// The strings are canonically equivalent, i.e. their NFD is the same.
+    Canonical,
+    /// The strings are compatibility equivalent, i.e. their NFKD is the
+    /// same.
+    Compatibility,
+    /// The strings only differ by case, up to canonical equivalence, i.e.
+    /// the canonical caseless match of D145: the NFD of the full case
+    /// folding of their NFD is the same.
+    Caseless,
+    /// The identifier caseless match of
+    /// [UAX #31](https://www.unicode.org/reports/tr31/), i.e. the
+    /// NFKC_Casefold of their NFD is the same.
+    Identifier,
+}
+
+impl Equivalence {
+    pub const NAMES: &[&str] = &[
+        "exact",
+        "canonical",
+        "compatibility",
+        "caseless",
+        "identifier",
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "exact" => Some(Equivalence::Exact),
+            "canonical" => Some(Equivalence::Canonical),
+            "compatibility" => Some(Equivalence::Compatibility),
+            "caseless" => Some(Equivalence::Caseless),
+            "identifier" => Some(Equivalence::Identifier),
+            _ => None,
+        }
+    }
+
+    /// Maps the text to a key, such that two strings are equivalent if and
+    /// only if their keys are equal.
+    pub fn key(&self, text: &str) -> String {
+        match self {
+            Equivalence::Exact => text.to_owned(),
+            Equivalence::Canonical => normalize(text, NormalizationForm::Nfd),
+            Equivalence::Compatibility => normalize(text, NormalizationForm::Nfkd),
+            Equivalence::Caseless => normalize(
+                &casefold(&normalize(text, NormalizationForm::Nfd), false, false),
+                NormalizationForm::Nfd,
+            ),
+            Equivalence::Identifier => {
+                nfkc_casefold(&normalize(text, NormalizationForm::Nfd), false)
+            }
+        }
+    }
+}
+
+/// The result of comparing two strings.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Comparison {
+    pub equal: bool,
+    /// The byte offsets in each string of the first grapheme cluster from
+    /// which they differ, unless they are equivalent.
+    pub mismatch: Option<(usize, usize)>,
+}
+
+/// Compares the two strings under the given equivalence.
+///
+/// Where they differ, everything before the reported offsets is equivalent.
+/// The offsets are found by mapping each grapheme cluster to its key on its
+/// own, since normalization and case folding barely ever look across
+/// grapheme cluster boundaries, so the keys can be traced back to the
+/// clusters they come from.
+pub fn compare(left: &str, right: &str, equivalence: Equivalence) -> Comparison {
+    if equivalence.key(left) == equivalence.key(right) {
+        return Comparison {
+            equal: true,
+            mismatch: None,
+        };
+    }
+
+    let left_keys = cluster_keys(left, equivalence);
+    let right_keys = cluster_keys(right, equivalence);
+    let mut common = left_keys
+        .iter()
+        .zip(&right_keys)
+        .take_while(|(left, right)| left.0 == right.0)
+        .count();
+
+    // back up to the start of a cluster in both strings, so that a cluster
+    // which only partially matches is reported as a whole
+    let starts_cluster = |keys: &[KeyChar], idx: usize| keys.get(idx).is_none_or(|key| key.2);
+
+    while common > 0 && !(starts_cluster(&left_keys, common) && starts_cluster(&right_keys, common))
+    {
+        common -= 1;
+    }
+
+    let offset_of = |keys: &[KeyChar], text: &str| keys.get(common).map_or(text.len(), |key| key.1);
+
+    Comparison {
+        equal: false,
+        mismatch: Some((offset_of(&left_keys, left), offset_of(&right_keys, right))),
+    }
+}
+
+/// A character of the key of some text, along with the byte offset of the
+/// grapheme cluster it comes from, and whether it is the first character of
+/// that cluster's key.
+type KeyChar = (char, usize, bool);
+
+fn cluster_keys(text: &str, equivalence: Equivalence) -> Vec<KeyChar> {
+    let mut keys = Vec::with_capacity(text.len());
+
+    for (offset, cluster) in graphemes(text) {
+        for (idx, ch) in equivalence.key(cluster).chars().enumerate() {
+            keys.push((ch, offset, idx == 0));
+        }
+    }
+
+    keys
+}
@@ -5,10 +5,158 @@ pub mod commands {
         pub mod flags {
             pub const ENCODING: &str = "encoding";
             pub const IGNORE_BOM: &str = "ignore-bom";
+            pub const PROPERTY_FLAGS: &str = "property-flags";
+            pub const GRAPHEMES: &str = "graphemes";
+            pub const STRICT: &str = "strict";
+            pub const REPORT_INVALID: &str = "report-invalid";
         }
 
         pub mod defaults {
             pub const ENCODING: &str = "utf8";
         }
     }
+
+    pub mod lookup {
+        pub const NAME: &str = "unicode lookup";
+    }
+
+    pub mod search {
+        pub const NAME: &str = "unicode search";
+
+        pub mod flags {
+            pub const BLOCK: &str = "block";
+            pub const SCRIPT: &str = "script";
+            pub const CATEGORY: &str = "category";
+            pub const FUZZY: &str = "fuzzy";
+        }
+    }
+
+    pub mod blocks {
+        pub const NAME: &str = "unicode blocks";
+    }
+
+    pub mod scripts {
+        pub const NAME: &str = "unicode scripts";
+
+        pub mod flags {
+            pub const EXTENSIONS: &str = "extensions";
+        }
+    }
+
+    pub mod check_version {
+        pub const NAME: &str = "unicode check-version";
+    }
+
+    pub mod width {
+        pub const NAME: &str = "unicode width";
+
+        pub mod flags {
+            pub const AMBIGUOUS_WIDE: &str = "ambiguous-wide";
+        }
+    }
+
+    pub mod emoji {
+        pub const NAME: &str = "unicode emoji";
+    }
+
+    pub mod normalize {
+        pub const NAME: &str = "unicode normalize";
+
+        pub mod flags {
+            pub const FORM: &str = "form";
+        }
+
+        pub mod defaults {
+            pub const FORM: &str = "nfc";
+        }
+    }
+
+    pub mod is_normalized {
+        pub const NAME: &str = "unicode is-normalized";
+    }
+
+    pub mod graphemes {
+        pub const NAME: &str = "unicode graphemes";
+    }
+
+    pub mod words {
+        pub const NAME: &str = "unicode words";
+
+        pub mod flags {
+            pub const WORDS_ONLY: &str = "words-only";
+        }
+    }
+
+    pub mod sentences {
+        pub const NAME: &str = "unicode sentences";
+    }
+
+    pub mod line_breaks {
+        pub const NAME: &str = "unicode line-breaks";
+    }
+
+    pub mod wrap {
+        pub const NAME: &str = "unicode wrap";
+
+        pub mod flags {
+            pub const WIDTH: &str = "width";
+            pub const AMBIGUOUS_WIDE: &str = "ambiguous-wide";
+        }
+    }
+
+    pub mod bidi {
+        pub const NAME: &str = "unicode bidi";
+
+        pub mod flags {
+            pub const DIRECTION: &str = "direction";
+        }
+
+        pub mod defaults {
+            pub const DIRECTION: &str = "auto";
+        }
+    }
+
+    pub mod upcase {
+        pub const NAME: &str = "unicode upcase";
+
+        pub mod flags {
+            pub const LOCALE: &str = "locale";
+        }
+    }
+
+    pub mod downcase {
+        pub const NAME: &str = "unicode downcase";
+    }
+
+    pub mod titlecase {
+        pub const NAME: &str = "unicode titlecase";
+    }
+
+    pub mod casefold {
+        pub const NAME: &str = "unicode casefold";
+
+        pub mod flags {
+            pub const SIMPLE: &str = "simple";
+        }
+    }
+
+    pub mod compare {
+        pub const NAME: &str = "unicode compare";
+
+        pub mod flags {
+            pub const MODE: &str = "mode";
+        }
+
+        pub mod defaults {
+            pub const MODE: &str = "canonical";
+        }
+    }
+
+    pub mod nfkc_casefold {
+        pub const NAME: &str = "unicode nfkc-casefold";
+
+        pub mod flags {
+            pub const SIMPLE: &str = "simple";
+        }
+    }
 }
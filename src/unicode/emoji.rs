@@ -0,0 +1,192 @@
+use nu_plugin_unicode_ucd::codegen::emoji_properties::{
+    EMOJI, EMOJI_MODIFIER, EMOJI_MODIFIER_BASE, EMOJI_PRESENTATION, EMOJI_VARIATION_BASES,
+};
+
+const ZWJ: char = '\u{200D}';
+const TEXT_PRESENTATION_SELECTOR: char = '\u{FE0E}';
+const EMOJI_PRESENTATION_SELECTOR: char = '\u{FE0F}';
+const COMBINING_ENCLOSING_KEYCAP: char = '\u{20E3}';
+const CANCEL_TAG: char = '\u{E007F}';
+
+/// The kinds of emoji sequence, as defined in
+/// [UTS #51, section 1.4](https://www.unicode.org/reports/tr51/#Definitions).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EmojiSequenceKind {
+    /// A single character which is displayed as emoji by default.
+    Basic,
+    /// A character followed by the emoji presentation selector.
+    Presentation,
+    /// A digit, `#` or `*` followed by the emoji presentation selector and
+    /// the combining enclosing keycap.
+    Keycap,
+    /// A pair of regional indicators.
+    Flag,
+    /// A character followed by an emoji modifier, i.e. a skin tone.
+    Modifier,
+    /// A character or sequence followed by tag characters, such as the
+    /// subdivision flags.
+    Tag,
+    /// Any of the above, joined by ZERO WIDTH JOINER.
+    Zwj,
+}
+
+impl EmojiSequenceKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            EmojiSequenceKind::Basic => "basic",
+            EmojiSequenceKind::Presentation => "presentation",
+            EmojiSequenceKind::Keycap => "keycap",
+            EmojiSequenceKind::Flag => "flag",
+            EmojiSequenceKind::Modifier => "modifier",
+            EmojiSequenceKind::Tag => "tag",
+            EmojiSequenceKind::Zwj => "zwj",
+        }
+    }
+}
+
+/// An emoji sequence found in some text.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmojiSequence<'a> {
+    /// The index of the sequence's first character in the text.
+    pub index: usize,
+    pub text: &'a str,
+    pub kind: EmojiSequenceKind,
+}
+
+/// What a single element of a sequence turned out to be. Characters which
+/// are displayed as text by default are only emoji when they are part of a
+/// longer sequence, so they are kept apart from the rest.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Element {
+    Text,
+    Emoji(EmojiSequenceKind),
+}
+
+/// Finds the emoji sequences in the text, longest first, skipping over
+/// anything which is not emoji.
+pub fn emoji_sequences(text: &str) -> Vec<EmojiSequence<'_>> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut sequences = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match sequence(&chars, i) {
+            Some((end, kind)) => {
+                let start_byte = chars[i].0;
+                let end_byte = chars.get(end).map_or(text.len(), |(offset, _)| *offset);
+
+                sequences.push(EmojiSequence {
+                    index: i,
+                    text: &text[start_byte..end_byte],
+                    kind,
+                });
+
+                i = end;
+            }
+            None => i += 1,
+        }
+    }
+
+    sequences
+}
+
+/// Matches the longest emoji sequence starting at `start`, and returns the
+/// index just past its end, along with its kind.
+fn sequence(chars: &[(usize, char)], start: usize) -> Option<(usize, EmojiSequenceKind)> {
+    let ch = |i: usize| chars.get(i).map(|(_, ch)| *ch);
+    let (mut end, mut found) = element(chars, start)?;
+
+    let tag_spec = |ch: char| ('\u{E0020}'..='\u{E007E}').contains(&ch);
+
+    if ch(end).is_some_and(tag_spec) && is_tag_base(found) {
+        let mut tag_end = end;
+
+        while ch(tag_end).is_some_and(tag_spec) {
+            tag_end += 1;
+        }
+
+        if ch(tag_end) == Some(CANCEL_TAG) {
+            end = tag_end + 1;
+            found = Element::Emoji(EmojiSequenceKind::Tag);
+        }
+    }
+
+    while is_zwj_element(found) && ch(end) == Some(ZWJ) {
+        match element(chars, end + 1) {
+            Some((next_end, next)) if is_zwj_element(next) => {
+                end = next_end;
+                found = Element::Emoji(EmojiSequenceKind::Zwj);
+            }
+            _ => break,
+        }
+    }
+
+    match found {
+        Element::Emoji(kind) => Some((end, kind)),
+        Element::Text => None,
+    }
+}
+
+/// Whether the element may be joined to others with ZWJ. Characters which
+/// are displayed as text by default need the emoji presentation selector to
+/// be joined.
+fn is_zwj_element(element: Element) -> bool {
+    matches!(
+        element,
+        Element::Emoji(
+            EmojiSequenceKind::Basic
+                | EmojiSequenceKind::Presentation
+                | EmojiSequenceKind::Modifier
+                | EmojiSequenceKind::Zwj
+        )
+    )
+}
+
+/// Whether the element may be the base of a tag sequence.
+fn is_tag_base(element: Element) -> bool {
+    element == Element::Text || is_zwj_element(element)
+}
+
+/// Matches a single character, keycap, flag, modifier or presentation
+/// sequence starting at `start`.
+fn element(chars: &[(usize, char)], start: usize) -> Option<(usize, Element)> {
+    let ch = |i: usize| chars.get(i).map(|(_, ch)| *ch);
+    let base = ch(start)?;
+    let cp = base as u32;
+
+    if matches!(base, '0'..='9' | '#' | '*')
+        && ch(start + 1) == Some(EMOJI_PRESENTATION_SELECTOR)
+        && ch(start + 2) == Some(COMBINING_ENCLOSING_KEYCAP)
+    {
+        return Some((start + 3, Element::Emoji(EmojiSequenceKind::Keycap)));
+    }
+
+    if is_regional_indicator(base) {
+        // a lone regional indicator is not a flag
+        return ch(start + 1)
+            .filter(|next| is_regional_indicator(*next))
+            .map(|_| (start + 2, Element::Emoji(EmojiSequenceKind::Flag)));
+    }
+
+    if !EMOJI.contains(cp) {
+        return None;
+    }
+
+    match ch(start + 1) {
+        Some(next) if EMOJI_MODIFIER.contains(next as u32) && EMOJI_MODIFIER_BASE.contains(cp) => {
+            Some((start + 2, Element::Emoji(EmojiSequenceKind::Modifier)))
+        }
+        Some(EMOJI_PRESENTATION_SELECTOR) if EMOJI_VARIATION_BASES.contains(cp) => {
+            Some((start + 2, Element::Emoji(EmojiSequenceKind::Presentation)))
+        }
+        Some(TEXT_PRESENTATION_SELECTOR) => None,
+        _ if EMOJI_PRESENTATION.contains(cp) => {
+            Some((start + 1, Element::Emoji(EmojiSequenceKind::Basic)))
+        }
+        _ => Some((start + 1, Element::Text)),
+    }
+}
+
+fn is_regional_indicator(ch: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&ch)
+}
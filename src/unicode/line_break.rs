@@ -0,0 +1,401 @@
+use nu_plugin_unicode_ucd::{
+    codegen::{
+        break_properties::line_break_of, east_asian_widths::east_asian_width_of,
+        emoji_properties::EXTENDED_PICTOGRAPHIC, unicode_data::general_category_of,
+    },
+    types::break_properties::LineBreak as Lb,
+};
+
+use crate::unicode::{segmentation::grapheme::graphemes, width::str_width};
+
+const DOTTED_CIRCLE: char = '\u{25CC}';
+
+/// Whether a line may or must be broken at some position.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BreakOpportunity {
+    /// The line must be broken here, e.g. after a line feed.
+    Mandatory,
+    /// The line may be broken here, if it would be too long otherwise.
+    Allowed,
+}
+
+impl BreakOpportunity {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BreakOpportunity::Mandatory => "mandatory",
+            BreakOpportunity::Allowed => "allowed",
+        }
+    }
+}
+
+/// A character, or a character followed by the combining marks which LB9
+/// attaches to it, which the pair rules treat as one.
+#[derive(Clone, Copy, Debug)]
+struct Unit {
+    class: Lb,
+    /// The first character, whose other properties some rules look at.
+    base: char,
+}
+
+impl Unit {
+    fn is_east_asian(&self) -> bool {
+        matches!(east_asian_width_of(self.base as u32), "F" | "W" | "H")
+    }
+
+    fn is_quote(&self, category: &str) -> bool {
+        self.class == Lb::Qu && general_category_of(self.base as u32) == category
+    }
+
+    /// Whether this matches `(AK | ◌ | AS)`, for LB28a.
+    fn is_aksara(&self) -> bool {
+        matches!(self.class, Lb::Ak | Lb::As) || self.base == DOTTED_CIRCLE
+    }
+}
+
+/// Finds the line break opportunities in the text, as per the rules in
+/// [UAX #14, section 6.1](https://www.unicode.org/reports/tr14/#BreakingRules),
+/// as byte offsets along with whether the break is mandatory. The end of the
+/// text is always a mandatory break, unless the text is empty.
+pub fn line_breaks(text: &str) -> Vec<(usize, BreakOpportunity)> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let classes: Vec<Lb> = chars.iter().map(|(_, ch)| resolve_class(*ch)).collect();
+
+    // LB9 and LB10: combining marks and ZWJ take on the class of the
+    // character before them, unless that is a space or a line break, in
+    // which case they are treated as AL
+    let mut units: Vec<Unit> = Vec::with_capacity(chars.len());
+    let mut unit_of = Vec::with_capacity(chars.len());
+
+    for (i, (_, ch)) in chars.iter().enumerate() {
+        let attaches = matches!(classes[i], Lb::Cm | Lb::Zwj)
+            && units.last().is_some_and(|unit: &Unit| {
+                !matches!(
+                    unit.class,
+                    Lb::Bk | Lb::Cr | Lb::Lf | Lb::Nl | Lb::Sp | Lb::Zw
+                )
+            });
+
+        if !attaches {
+            let class = match classes[i] {
+                Lb::Cm | Lb::Zwj => Lb::Al,
+                class => class,
+            };
+
+            units.push(Unit { class, base: *ch });
+        }
+
+        unit_of.push(units.len() - 1);
+    }
+
+    let mut breaks = Vec::new();
+    // the number of regional indicators directly before the next unit
+    let mut regional_indicators =
+        usize::from(units.first().is_some_and(|unit| unit.class == Lb::Ri));
+
+    for i in 1..chars.len() {
+        let new_unit = unit_of[i] != unit_of[i - 1];
+        let opportunity = match before_pair_rules(&classes, i) {
+            Some(opportunity) => opportunity,
+            // LB9: nothing breaks before an attached combining mark
+            None if !new_unit => None,
+            None => pair_rules(&units, unit_of[i], regional_indicators),
+        };
+
+        if let Some(opportunity) = opportunity {
+            breaks.push((chars[i].0, opportunity));
+        }
+
+        if new_unit {
+            match units[unit_of[i]].class {
+                Lb::Ri => regional_indicators += 1,
+                _ => regional_indicators = 0,
+            }
+        }
+    }
+
+    // LB3
+    if !chars.is_empty() {
+        breaks.push((text.len(), BreakOpportunity::Mandatory));
+    }
+
+    breaks
+}
+
+/// LB1: resolves the classes whose behaviour is left to tailoring to the
+/// ones they default to.
+fn resolve_class(ch: char) -> Lb {
+    match line_break_of(ch as u32) {
+        Lb::Ai | Lb::Sg | Lb::Xx => Lb::Al,
+        Lb::Sa if matches!(general_category_of(ch as u32), "Mn" | "Mc") => Lb::Cm,
+        Lb::Sa => Lb::Al,
+        Lb::Cj => Lb::Ns,
+        class => class,
+    }
+}
+
+/// Applies LB4 to LB8a, which look at the characters on either side of the
+/// position before combining marks are attached. `Some(None)` means that
+/// there is no break.
+fn before_pair_rules(classes: &[Lb], i: usize) -> Option<Option<BreakOpportunity>> {
+    let (prev, next) = (classes[i - 1], classes[i]);
+
+    match (prev, next) {
+        // LB4
+        (Lb::Bk, _) => Some(Some(BreakOpportunity::Mandatory)),
+        // LB5
+        (Lb::Cr, Lb::Lf) => Some(None),
+        (Lb::Cr | Lb::Lf | Lb::Nl, _) => Some(Some(BreakOpportunity::Mandatory)),
+        // LB6
+        (_, Lb::Bk | Lb::Cr | Lb::Lf | Lb::Nl) => Some(None),
+        // LB7
+        (_, Lb::Sp | Lb::Zw) => Some(None),
+        // LB8
+        _ if classes[..i]
+            .iter()
+            .rev()
+            .find(|class| **class != Lb::Sp)
+            .is_some_and(|class| *class == Lb::Zw) =>
+        {
+            Some(Some(BreakOpportunity::Allowed))
+        }
+        // LB8a
+        (Lb::Zwj, _) => Some(None),
+        _ => None,
+    }
+}
+
+/// Applies LB11 to LB31 at the position before the unit at `u`, given the
+/// number of regional indicators directly before it.
+fn pair_rules(units: &[Unit], u: usize, regional_indicators: usize) -> Option<BreakOpportunity> {
+    let class = |idx: Option<usize>| idx.map(|idx| units[idx].class);
+    // the index of the first unit before `idx` which isn't a space
+    let skip_spaces = |idx: usize| (0..=idx).rev().find(|j| units[*j].class != Lb::Sp);
+
+    let (prev, next) = (units[u - 1], units[u]);
+    let prev2 = u.checked_sub(2).map(|idx| units[idx]);
+    let next2 = units.get(u + 1);
+    let before_spaces = class(skip_spaces(u - 1));
+
+    let no_break = match (prev.class, next.class) {
+        // LB11
+        (_, Lb::Wj) | (Lb::Wj, _) => true,
+        // LB12
+        (Lb::Gl, _) => true,
+        // LB12a
+        (p, Lb::Gl) if !matches!(p, Lb::Sp | Lb::Ba | Lb::Hy | Lb::Hh) => true,
+        // LB13
+        (_, Lb::Cl | Lb::Cp | Lb::Ex | Lb::Sy) => true,
+        // LB14
+        _ if before_spaces == Some(Lb::Op) => true,
+        // LB15a
+        _ if skip_spaces(u - 1).is_some_and(|q| {
+            units[q].is_quote("Pi")
+                && (q == 0
+                    || matches!(
+                        units[q - 1].class,
+                        Lb::Bk
+                            | Lb::Cr
+                            | Lb::Lf
+                            | Lb::Nl
+                            | Lb::Op
+                            | Lb::Qu
+                            | Lb::Gl
+                            | Lb::Sp
+                            | Lb::Zw
+                    ))
+        }) =>
+        {
+            true
+        }
+        // LB15b
+        _ if next.is_quote("Pf")
+            && next2.is_none_or(|unit| {
+                matches!(
+                    unit.class,
+                    Lb::Sp
+                        | Lb::Gl
+                        | Lb::Wj
+                        | Lb::Cl
+                        | Lb::Qu
+                        | Lb::Cp
+                        | Lb::Ex
+                        | Lb::Is
+                        | Lb::Sy
+                        | Lb::Bk
+                        | Lb::Cr
+                        | Lb::Lf
+                        | Lb::Nl
+                        | Lb::Zw
+                )
+            }) =>
+        {
+            true
+        }
+        // LB15c
+        (Lb::Sp, Lb::Is) if next2.is_some_and(|unit| unit.class == Lb::Nu) => false,
+        // LB15d
+        (_, Lb::Is) => true,
+        // LB16
+        (_, Lb::Ns) if matches!(before_spaces, Some(Lb::Cl | Lb::Cp)) => true,
+        // LB17
+        (_, Lb::B2) if before_spaces == Some(Lb::B2) => true,
+        // LB18
+        (Lb::Sp, _) => false,
+        // LB19
+        (_, Lb::Qu) if !next.is_quote("Pi") => true,
+        (Lb::Qu, _) if !prev.is_quote("Pf") => true,
+        // LB19a
+        (_, Lb::Qu) if !prev.is_east_asian() => true,
+        (_, Lb::Qu) if next2.is_none_or(|unit| !unit.is_east_asian()) => true,
+        (Lb::Qu, _) if !next.is_east_asian() => true,
+        (Lb::Qu, _) if prev2.is_none_or(|unit| !unit.is_east_asian()) => true,
+        // LB20
+        (_, Lb::Cb) | (Lb::Cb, _) => false,
+        // LB20a
+        (Lb::Hy | Lb::Hh, Lb::Al | Lb::Hl)
+            if prev2.is_none_or(|unit| {
+                matches!(
+                    unit.class,
+                    Lb::Bk | Lb::Cr | Lb::Lf | Lb::Nl | Lb::Sp | Lb::Zw | Lb::Cb | Lb::Gl
+                )
+            }) =>
+        {
+            true
+        }
+        // LB21
+        (_, Lb::Ba | Lb::Hh | Lb::Hy | Lb::Ns) | (Lb::Bb, _) => true,
+        // LB21a
+        (Lb::Hy | Lb::Hh, n) if n != Lb::Hl && class(u.checked_sub(2)) == Some(Lb::Hl) => true,
+        // LB21b
+        (Lb::Sy, Lb::Hl) => true,
+        // LB22
+        (_, Lb::In) => true,
+        // LB23
+        (Lb::Al | Lb::Hl, Lb::Nu) | (Lb::Nu, Lb::Al | Lb::Hl) => true,
+        // LB23a
+        (Lb::Pr, Lb::Id | Lb::Eb | Lb::Em) | (Lb::Id | Lb::Eb | Lb::Em, Lb::Po) => true,
+        // LB24
+        (Lb::Pr | Lb::Po, Lb::Al | Lb::Hl) | (Lb::Al | Lb::Hl, Lb::Pr | Lb::Po) => true,
+        // LB25
+        (p, Lb::Po | Lb::Pr) if ends_numeric(units, u, matches!(p, Lb::Cl | Lb::Cp)) => true,
+        (Lb::Po | Lb::Pr, Lb::Op)
+            if next2.is_some_and(|unit| unit.class == Lb::Nu)
+                || (next2.is_some_and(|unit| unit.class == Lb::Is)
+                    && units.get(u + 2).is_some_and(|unit| unit.class == Lb::Nu)) =>
+        {
+            true
+        }
+        (Lb::Po | Lb::Pr | Lb::Hy | Lb::Is, Lb::Nu) => true,
+        (_, Lb::Nu) if ends_numeric(units, u, false) => true,
+        // LB26
+        (Lb::Jl, Lb::Jl | Lb::Jv | Lb::H2 | Lb::H3)
+        | (Lb::Jv | Lb::H2, Lb::Jv | Lb::Jt)
+        | (Lb::Jt | Lb::H3, Lb::Jt) => true,
+        // LB27
+        (Lb::Jl | Lb::Jv | Lb::Jt | Lb::H2 | Lb::H3, Lb::Po)
+        | (Lb::Pr, Lb::Jl | Lb::Jv | Lb::Jt | Lb::H2 | Lb::H3) => true,
+        // LB28
+        (Lb::Al | Lb::Hl, Lb::Al | Lb::Hl) => true,
+        // LB28a
+        (Lb::Ap, _) if next.is_aksara() => true,
+        (_, Lb::Vf | Lb::Vi) if prev.is_aksara() => true,
+        (Lb::Vi, _)
+            if prev2.is_some_and(|unit| unit.is_aksara())
+                && (next.class == Lb::Ak || next.base == DOTTED_CIRCLE) =>
+        {
+            true
+        }
+        _ if prev.is_aksara()
+            && next.is_aksara()
+            && next2.is_some_and(|unit| unit.class == Lb::Vf) =>
+        {
+            true
+        }
+        // LB29
+        (Lb::Is, Lb::Al | Lb::Hl) => true,
+        // LB30
+        (Lb::Al | Lb::Hl | Lb::Nu, Lb::Op) if !next.is_east_asian() => true,
+        (Lb::Cp, Lb::Al | Lb::Hl | Lb::Nu) if !prev.is_east_asian() => true,
+        // LB30a
+        (Lb::Ri, Lb::Ri) => !regional_indicators.is_multiple_of(2),
+        // LB30b
+        (Lb::Eb, Lb::Em) => true,
+        (_, Lb::Em)
+            if EXTENDED_PICTOGRAPHIC.contains(prev.base as u32)
+                && general_category_of(prev.base as u32) == "Cn" =>
+        {
+            true
+        }
+        // LB31
+        _ => false,
+    };
+
+    (!no_break).then_some(BreakOpportunity::Allowed)
+}
+
+/// Whether the units before `u` match `NU (SY | IS)*`, optionally followed
+/// by a closing punctuation, for LB25.
+fn ends_numeric(units: &[Unit], u: usize, closed: bool) -> bool {
+    let end = if closed { u - 1 } else { u };
+
+    units[..end]
+        .iter()
+        .rev()
+        .find(|unit| !matches!(unit.class, Lb::Sy | Lb::Is))
+        .is_some_and(|unit| unit.class == Lb::Nu)
+}
+
+/// Wraps the text so that no line is wider than `width` terminal columns,
+/// breaking lines at the break opportunities of UAX #14. Spaces at the end
+/// of a wrapped line are dropped.
+///
+/// Words which are too wide for a line of their own are broken between
+/// grapheme clusters.
+pub fn wrap(text: &str, width: usize, ambiguous_wide: bool) -> String {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut line_width = 0;
+    let mut start = 0;
+
+    for (end, opportunity) in line_breaks(text) {
+        let segment = &text[start..end];
+        start = end;
+
+        let content = segment.trim_end_matches(|ch: char| {
+            matches!(line_break_of(ch as u32), Lb::Bk | Lb::Cr | Lb::Lf | Lb::Nl)
+        });
+        let visible_width = str_width(content.trim_end(), ambiguous_wide);
+
+        if line_width + visible_width > width && !line.is_empty() {
+            lines.push(line.trim_end().to_owned());
+            line.clear();
+            line_width = 0;
+        }
+
+        if visible_width > width {
+            for (_, cluster) in graphemes(content) {
+                let cluster_width = str_width(cluster, ambiguous_wide);
+
+                if line_width + cluster_width > width && !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                    line_width = 0;
+                }
+
+                line.push_str(cluster);
+                line_width += cluster_width;
+            }
+        } else {
+            line.push_str(content);
+            line_width += str_width(content, ambiguous_wide);
+        }
+
+        if opportunity == BreakOpportunity::Mandatory && content.len() != segment.len() {
+            lines.push(line.trim_end().to_owned());
+            line.clear();
+            line_width = 0;
+        }
+    }
+
+    lines.push(line);
+    lines.join("\n")
+}
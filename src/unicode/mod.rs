@@ -1,13 +1,46 @@
 use nu_plugin::{Plugin, PluginCommand};
 
+pub mod bidi;
+pub mod casing;
 pub mod commands;
+pub mod comparison;
 pub mod constants;
+pub mod emoji;
+pub mod line_break;
+pub mod names;
+pub mod normalization;
+pub mod properties;
+pub mod segmentation;
+pub mod width;
 
 pub struct Unicode;
 
 impl Plugin for Unicode {
     fn commands(&self) -> Vec<Box<dyn PluginCommand<Plugin = Self>>> {
-        vec![Box::new(commands::chars::UnicodeChars)]
+        vec![
+            Box::new(commands::chars::UnicodeChars),
+            Box::new(commands::lookup::UnicodeLookup),
+            Box::new(commands::search::UnicodeSearch),
+            Box::new(commands::blocks::UnicodeBlocks),
+            Box::new(commands::scripts::UnicodeScripts),
+            Box::new(commands::check_version::UnicodeCheckVersion),
+            Box::new(commands::width::UnicodeWidth),
+            Box::new(commands::emoji::UnicodeEmoji),
+            Box::new(commands::normalize::UnicodeNormalize),
+            Box::new(commands::is_normalized::UnicodeIsNormalized),
+            Box::new(commands::graphemes::UnicodeGraphemes),
+            Box::new(commands::words::UnicodeWords),
+            Box::new(commands::sentences::UnicodeSentences),
+            Box::new(commands::line_breaks::UnicodeLineBreaks),
+            Box::new(commands::wrap::UnicodeWrap),
+            Box::new(commands::bidi::UnicodeBidi),
+            Box::new(commands::upcase::UnicodeUpcase),
+            Box::new(commands::downcase::UnicodeDowncase),
+            Box::new(commands::titlecase::UnicodeTitlecase),
+            Box::new(commands::casefold::UnicodeCasefold),
+            Box::new(commands::compare::UnicodeCompare),
+            Box::new(commands::nfkc_casefold::UnicodeNfkcCasefold),
+        ]
     }
 
     fn version(&self) -> String {
@@ -0,0 +1,180 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use nu_plugin_unicode_ucd::{
+    codegen::{
+        name_aliases::NAME_ALIASES,
+        named_sequences::NAMED_SEQUENCES,
+        names::{NAME_PATTERNS, name_of},
+        unicode_data::UNICODE_DATA,
+    },
+    hangul,
+};
+
+/// What a name resolved to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NameMatch {
+    /// The formal name of a character, either stored or derived.
+    Name(u32),
+    /// One of the aliases from `NameAliases.txt`.
+    Alias(u32),
+    /// A sequence from `NamedSequences.txt`.
+    NamedSequence(&'static str, &'static [u32]),
+    /// A codepoint written in `U+XXXX` notation.
+    Codepoint(u32),
+}
+
+impl NameMatch {
+    pub fn codepoints(&self) -> Vec<u32> {
+        match self {
+            NameMatch::Name(cp) | NameMatch::Alias(cp) | NameMatch::Codepoint(cp) => vec![*cp],
+            NameMatch::NamedSequence(_, cps) => cps.to_vec(),
+        }
+    }
+
+    /// The name of the match: the formal name of the character, or the name
+    /// of the sequence.
+    pub fn name(&self) -> Option<String> {
+        match self {
+            NameMatch::Name(cp) | NameMatch::Alias(cp) | NameMatch::Codepoint(cp) => {
+                name_of(*cp).map(Into::into)
+            }
+            NameMatch::NamedSequence(name, _) => Some((*name).to_owned()),
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            NameMatch::Name(_) => "name",
+            NameMatch::Alias(_) => "alias",
+            NameMatch::NamedSequence(..) => "named sequence",
+            NameMatch::Codepoint(_) => "codepoint",
+        }
+    }
+}
+
+/// Computes the key of a name under the loose matching rule
+/// [UAX44-LM2](https://www.unicode.org/reports/tr44/#UAX44-LM2): case,
+/// whitespace, underscores and medial hyphens are ignored.
+///
+/// The one exception is U+1180 HANGUL JUNGSEONG O-E, which would otherwise
+/// collide with U+116C HANGUL JUNGSEONG OE, so its hyphen is kept.
+pub fn loose_match_key(name: &str) -> String {
+    let chars: Vec<char> = name.trim().chars().collect();
+    let mut key = String::with_capacity(chars.len());
+
+    for (i, &ch) in chars.iter().enumerate() {
+        match ch {
+            '_' => {}
+            ch if ch.is_whitespace() => {}
+            '-' => {
+                let medial = i > 0
+                    && i + 1 < chars.len()
+                    && chars[i - 1].is_alphanumeric()
+                    && chars[i + 1].is_alphanumeric();
+
+                if !medial {
+                    key.push('-');
+                }
+            }
+            ch => key.extend(ch.to_uppercase()),
+        }
+    }
+
+    if key == "HANGULJUNGSEONGOE" {
+        let spaced: String = name
+            .to_uppercase()
+            .chars()
+            .filter(|ch| !ch.is_whitespace() && *ch != '_')
+            .collect();
+
+        if spaced.ends_with("O-E") {
+            key.insert(key.len() - 1, '-');
+        }
+    }
+
+    key
+}
+
+fn index() -> &'static HashMap<String, NameMatch> {
+    static INDEX: OnceLock<HashMap<String, NameMatch>> = OnceLock::new();
+
+    INDEX.get_or_init(|| {
+        let mut index = HashMap::with_capacity(UNICODE_DATA.len() + hangul::S_COUNT as usize);
+
+        for (cp, data) in UNICODE_DATA.entries() {
+            if !data.name.starts_with('<') {
+                index.insert(loose_match_key(&data.name), NameMatch::Name(*cp));
+            }
+        }
+
+        // derived by rule NR1, so they're not in UNICODE_DATA
+        for cp in hangul::S_BASE..hangul::S_BASE + hangul::S_COUNT {
+            if let Some(name) = name_of(cp) {
+                index.insert(loose_match_key(&name), NameMatch::Name(cp));
+            }
+        }
+
+        for (cp, aliases) in NAME_ALIASES.entries() {
+            for alias in aliases.iter() {
+                index
+                    .entry(loose_match_key(alias.alias))
+                    .or_insert(NameMatch::Alias(*cp));
+            }
+        }
+
+        for (name, cps) in NAMED_SEQUENCES {
+            index
+                .entry(loose_match_key(name))
+                .or_insert(NameMatch::NamedSequence(name, cps));
+        }
+
+        index
+    })
+}
+
+/// Resolves a character name, alias, named sequence or `U+XXXX` codepoint
+/// notation, using loose matching.
+pub fn lookup(name: &str) -> Option<NameMatch> {
+    if let Some(cp) = parse_codepoint_notation(name) {
+        return Some(NameMatch::Codepoint(cp));
+    }
+
+    let key = loose_match_key(name);
+
+    if let Some(found) = index().get(&key) {
+        return Some(*found);
+    }
+
+    // names derived by rule NR2 are the pattern's prefix followed by the
+    // codepoint in hex, so they are matched by parsing them
+    NAME_PATTERNS.iter().find_map(|(_, _, prefix)| {
+        // the hyphen before the codepoint is medial in the full name
+        let hex = key.strip_prefix(&loose_match_key(prefix.trim_end_matches('-')))?;
+        let cp = parse_hex_codepoint(hex)?;
+
+        (NAME_PATTERNS.get(cp) == Some(prefix)).then_some(NameMatch::Name(cp))
+    })
+}
+
+fn parse_codepoint_notation(name: &str) -> Option<u32> {
+    let name = name.trim();
+    let hex = name
+        .strip_prefix("U+")
+        .or_else(|| name.strip_prefix("u+"))?;
+
+    parse_hex_codepoint(hex)
+}
+
+/// Parses a codepoint written the way the standard writes them: in hex, with
+/// at least four digits and no other leading zeros, so that e.g. `4E00`
+/// parses but `04E00` does not.
+fn parse_hex_codepoint(hex: &str) -> Option<u32> {
+    if !hex.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    u32::from_str_radix(hex, 16)
+        .ok()
+        .filter(|cp| *cp <= char::MAX as u32)
+        .filter(|cp| hex.eq_ignore_ascii_case(&format!("{cp:04X}")))
+}
@@ -0,0 +1,358 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::OnceLock,
+};
+
+use nu_plugin_unicode_ucd::{
+    codegen::{
+        normalization::{
+            COMPOSITION_EXCLUSIONS, NFC_QUICK_CHECKS, NFD_QUICK_CHECKS, NFKC_QUICK_CHECKS,
+            NFKD_QUICK_CHECKS, nfkc_casefold_of,
+        },
+        unicode_data::{UNICODE_DATA, canonical_combining_class_of, decomposition_of},
+    },
+    hangul,
+    types::{normalization::QuickCheck, ranges::RangeTable},
+};
+
+/// The four normalization forms of
+/// [UAX #15](https://www.unicode.org/reports/tr15/).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NormalizationForm {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+impl NormalizationForm {
+    pub const NAMES: &[&str] = &["nfc", "nfd", "nfkc", "nfkd"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "nfc" => Some(NormalizationForm::Nfc),
+            "nfd" => Some(NormalizationForm::Nfd),
+            "nfkc" => Some(NormalizationForm::Nfkc),
+            "nfkd" => Some(NormalizationForm::Nfkd),
+            _ => None,
+        }
+    }
+
+    /// Whether compatibility decompositions are applied, and not just
+    /// canonical ones.
+    pub fn is_compatibility(&self) -> bool {
+        matches!(self, NormalizationForm::Nfkc | NormalizationForm::Nfkd)
+    }
+
+    /// Whether the decomposed text is recomposed afterwards.
+    pub fn is_composed(&self) -> bool {
+        matches!(self, NormalizationForm::Nfc | NormalizationForm::Nfkc)
+    }
+
+    /// The quick check property of this form, e.g. NFC_QC.
+    pub fn quick_check_of(&self, codepoint: u32) -> QuickCheck {
+        let table: &RangeTable<QuickCheck> = match self {
+            NormalizationForm::Nfc => &NFC_QUICK_CHECKS,
+            NormalizationForm::Nfd => &NFD_QUICK_CHECKS,
+            NormalizationForm::Nfkc => &NFKC_QUICK_CHECKS,
+            NormalizationForm::Nfkd => &NFKD_QUICK_CHECKS,
+        };
+
+        table.get(codepoint).copied().unwrap_or_default()
+    }
+}
+
+/// Normalizes the text to the given form: it is fully decomposed, put into
+/// canonical order, and then recomposed for NFC and NFKC.
+pub fn normalize(text: &str, form: NormalizationForm) -> String {
+    let mut chars = Vec::with_capacity(text.len());
+
+    for ch in text.chars() {
+        decompose(ch, form.is_compatibility(), &mut chars);
+    }
+
+    reorder(&mut chars);
+
+    if form.is_composed() {
+        compose(&mut chars);
+    }
+
+    chars.into_iter().collect()
+}
+
+/// Determines whether the text is in the given form without normalizing it,
+/// as per [UAX #15, section 9](https://www.unicode.org/reports/tr15/#Detecting_Normalization_Forms).
+/// The answer may be `Maybe`, in which case only normalizing the text will
+/// tell.
+pub fn quick_check(text: &str, form: NormalizationForm) -> QuickCheck {
+    let mut last_ccc = 0;
+    let mut result = QuickCheck::Yes;
+
+    for ch in text.chars() {
+        let cp = ch as u32;
+        let ccc = canonical_combining_class_of(cp);
+
+        if last_ccc > ccc && ccc != 0 {
+            return QuickCheck::No;
+        }
+
+        match form.quick_check_of(cp) {
+            QuickCheck::No => return QuickCheck::No,
+            QuickCheck::Maybe => result = QuickCheck::Maybe,
+            QuickCheck::Yes => {}
+        }
+
+        last_ccc = ccc;
+    }
+
+    result
+}
+
+/// Determines whether the text is in the given form, only normalizing it
+/// when the quick check can't tell.
+pub fn is_normalized(text: &str, form: NormalizationForm) -> bool {
+    match quick_check(text, form) {
+        QuickCheck::Yes => true,
+        QuickCheck::No => false,
+        QuickCheck::Maybe => normalize(text, form) == text,
+    }
+}
+
+/// Maps the text to its NFKC_Casefold, or its NFKC_Simple_Casefold if
+/// `simple` is set: each character is replaced by its mapping from
+/// `DerivedNormalizationProps.txt`, and the result is normalized to NFC. This
+/// folds case, compatibility variants such as full-width letters, and drops
+/// default ignorable characters, so it makes for caseless lookup keys.
+pub fn nfkc_casefold(text: &str, simple: bool) -> String {
+    let mut folded = String::with_capacity(text.len());
+
+    for ch in text.chars() {
+        match nfkc_casefold_of(ch as u32, simple) {
+            Some(mapping) => folded.extend(mapping.iter().filter_map(|cp| char::from_u32(*cp))),
+            None => folded.push(ch),
+        }
+    }
+
+    normalize(&folded, NormalizationForm::Nfc)
+}
+
+/// Normalizes text which arrives in chunks, such as from a stream, giving
+/// the same result as normalizing all of the text at once.
+///
+/// Each chunk is normalized up to the last character which nothing before it
+/// can reorder or compose with, and the rest is held back until the next
+/// chunk arrives.
+#[derive(Debug)]
+pub struct Normalizer {
+    form: NormalizationForm,
+    pending: String,
+}
+
+impl Normalizer {
+    pub fn new(form: NormalizationForm) -> Self {
+        Normalizer {
+            form,
+            pending: String::new(),
+        }
+    }
+
+    /// Feeds the next chunk of text, and returns as much of the normalized
+    /// text as is now known.
+    pub fn push(&mut self, chunk: &str) -> String {
+        self.pending.push_str(chunk);
+
+        let split = self
+            .pending
+            .char_indices()
+            .rev()
+            .find(|(_, ch)| is_stable_boundary(*ch, self.form))
+            .map_or(0, |(idx, _)| idx);
+
+        let rest = self.pending.split_off(split);
+        let normalized = normalize(&self.pending, self.form);
+        self.pending = rest;
+
+        normalized
+    }
+
+    /// Normalizes whatever text is still held back.
+    pub fn finish(self) -> String {
+        normalize(&self.pending, self.form)
+    }
+}
+
+/// Whether normalization never changes text across the boundary before this
+/// character: it is a starter, it decomposes to a starter, and in the
+/// composed forms, that starter cannot combine with anything before it.
+fn is_stable_boundary(ch: char, form: NormalizationForm) -> bool {
+    if canonical_combining_class_of(ch as u32) != 0 {
+        return false;
+    }
+
+    let mut decomposed = Vec::new();
+    decompose(ch, form.is_compatibility(), &mut decomposed);
+
+    let first = decomposed[0] as u32;
+
+    canonical_combining_class_of(first) == 0
+        && !(form.is_composed() && compositions().seconds.contains(&first))
+}
+
+/// Appends the full decomposition of the character, applying mappings
+/// recursively until none are left.
+fn decompose(ch: char, compatibility: bool, out: &mut Vec<char>) {
+    let cp = ch as u32;
+
+    if let Some((l, v, t)) = hangul::decompose_syllable(cp) {
+        out.extend([l, v].into_iter().chain(t).filter_map(char::from_u32));
+        return;
+    }
+
+    match decomposition_of(cp) {
+        Some(decomposition) if compatibility || decomposition.tag.is_none() => {
+            for ch in decomposition
+                .mapping
+                .iter()
+                .filter_map(|cp| char::from_u32(*cp))
+            {
+                decompose(ch, compatibility, out);
+            }
+        }
+        _ => out.push(ch),
+    }
+}
+
+/// Puts each run of non-starters into canonical order, by sorting them on
+/// their canonical combining class. The sort is stable, so characters with
+/// the same class keep their order.
+fn reorder(chars: &mut [char]) {
+    let ccc = |ch: &char| canonical_combining_class_of(*ch as u32);
+    let mut start = 0;
+
+    while start < chars.len() {
+        if ccc(&chars[start]) == 0 {
+            start += 1;
+            continue;
+        }
+
+        let len = chars[start..]
+            .iter()
+            .position(|ch| ccc(ch) == 0)
+            .unwrap_or(chars.len() - start);
+
+        chars[start..start + len].sort_by_key(ccc);
+        start += len;
+    }
+}
+
+/// Applies the canonical composition algorithm to decomposed, reordered
+/// text: each character is combined with the last starter before it, unless
+/// a character in between blocks it.
+fn compose(chars: &mut Vec<char>) {
+    let mut out: Vec<char> = Vec::with_capacity(chars.len());
+    let mut starter: Option<usize> = None;
+    // the class of the last character after the starter, if there is one
+    let mut last_ccc: Option<u8> = None;
+
+    for ch in chars.drain(..) {
+        let ccc = canonical_combining_class_of(ch as u32);
+
+        if let Some(starter) = starter {
+            let blocked = last_ccc.is_some_and(|last| last == 0 || last >= ccc);
+
+            if !blocked && let Some(composed) = compose_pair(out[starter], ch) {
+                out[starter] = composed;
+                continue;
+            }
+        }
+
+        if ccc == 0 {
+            starter = Some(out.len());
+            last_ccc = None;
+        } else {
+            last_ccc = Some(ccc);
+        }
+
+        out.push(ch);
+    }
+
+    *chars = out;
+}
+
+/// Returns the primary composite of the pair, if there is one.
+fn compose_pair(first: char, second: char) -> Option<char> {
+    let (first, second) = (first as u32, second as u32);
+
+    let l_range = hangul::L_BASE..hangul::L_BASE + hangul::L_COUNT;
+    let v_range = hangul::V_BASE..hangul::V_BASE + hangul::V_COUNT;
+    let t_range = hangul::T_BASE + 1..hangul::T_BASE + hangul::T_COUNT;
+
+    if l_range.contains(&first) && v_range.contains(&second) {
+        let l_index = first - hangul::L_BASE;
+        let v_index = second - hangul::V_BASE;
+
+        return char::from_u32(
+            hangul::S_BASE + (l_index * hangul::V_COUNT + v_index) * hangul::T_COUNT,
+        );
+    }
+
+    if hangul::is_syllable(first)
+        && (first - hangul::S_BASE).is_multiple_of(hangul::T_COUNT)
+        && t_range.contains(&second)
+    {
+        return char::from_u32(first + second - hangul::T_BASE);
+    }
+
+    compositions()
+        .pairs
+        .get(&(first, second))
+        .and_then(|cp| char::from_u32(*cp))
+}
+
+struct Compositions {
+    /// The primary composites, by the pair they decompose to.
+    pairs: HashMap<(u32, u32), u32>,
+    /// Every character which is the second of a pair, i.e. which may combine
+    /// with a character before it.
+    seconds: HashSet<u32>,
+}
+
+/// The primary composites: every canonical decomposition to a pair, except
+/// for those with a Full_Composition_Exclusion. Singleton decompositions
+/// are already left out by only taking pairs.
+fn compositions() -> &'static Compositions {
+    static COMPOSITIONS: OnceLock<Compositions> = OnceLock::new();
+
+    COMPOSITIONS.get_or_init(|| {
+        let mut pairs = HashMap::new();
+
+        for (cp, data) in UNICODE_DATA.entries() {
+            let Some(decomposition) = &data.decomposition else {
+                continue;
+            };
+
+            let &[first, second] = decomposition.mapping else {
+                continue;
+            };
+
+            // non-starter decompositions are excluded too
+            let excluded = decomposition.tag.is_some()
+                || COMPOSITION_EXCLUSIONS.contains(*cp)
+                || data.canonical_combining_class != 0
+                || canonical_combining_class_of(first) != 0;
+
+            if !excluded {
+                pairs.insert((first, second), *cp);
+            }
+        }
+
+        let seconds = pairs
+            .keys()
+            .map(|(_, second)| *second)
+            .chain(hangul::V_BASE..hangul::V_BASE + hangul::V_COUNT)
+            .chain(hangul::T_BASE + 1..hangul::T_BASE + hangul::T_COUNT)
+            .collect();
+
+        Compositions { pairs, seconds }
+    })
+}
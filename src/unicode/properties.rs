@@ -0,0 +1,55 @@
+use nu_plugin_unicode_ucd::codegen::property_value_aliases::{
+    PROPERTY_VALUE_ALIASES, property_value_alias,
+};
+use nu_protocol::{IntoValue, Span, Value, record};
+
+/// Computes the key of a property value under the loose matching rule
+/// [UAX44-LM3](https://www.unicode.org/reports/tr44/#UAX44-LM3): case,
+/// whitespace, underscores and hyphens are ignored, as is an initial "is".
+pub fn loose_value_key(value: &str) -> String {
+    let key: String = value
+        .chars()
+        .filter(|ch| !ch.is_whitespace() && *ch != '_' && *ch != '-')
+        .flat_map(char::to_lowercase)
+        .collect();
+
+    match key.strip_prefix("is") {
+        Some(rest) if !rest.is_empty() => rest.to_owned(),
+        _ => key,
+    }
+}
+
+/// Describes a property value by both its abbreviated and long names, as
+/// listed in `PropertyValueAliases.txt`. Values without aliases use the given
+/// name for both.
+///
+/// `property` is the abbreviated property name, e.g. `sc` for Script.
+pub fn value_names(property: &str, value: &str, span: Span) -> Value {
+    let alias = property_value_alias(property, value);
+
+    record!(
+        "short" => alias.map_or(value, |alias| alias.abbreviation).into_value(span),
+        "long" => alias.map_or(value, |alias| alias.long).into_value(span),
+    )
+    .into_value(span)
+}
+
+/// Computes the loose matching key of a property value, given by any of its
+/// aliases. Values known to `PropertyValueAliases.txt` are keyed by their long
+/// name, so that all of their aliases have the same key.
+pub fn resolve_value_key(property: &str, value: &str) -> String {
+    let key = loose_value_key(value);
+
+    PROPERTY_VALUE_ALIASES
+        .iter()
+        .filter(|alias| alias.property == property)
+        .find(|alias| {
+            loose_value_key(alias.abbreviation) == key
+                || loose_value_key(alias.long) == key
+                || alias
+                    .aliases
+                    .iter()
+                    .any(|other| loose_value_key(other) == key)
+        })
+        .map_or(key, |alias| loose_value_key(alias.long))
+}
@@ -0,0 +1,169 @@
+use nu_plugin_unicode_ucd::{
+    codegen::{
+        break_properties::{grapheme_cluster_break_of, indic_conjunct_break_of},
+        emoji_properties::EXTENDED_PICTOGRAPHIC,
+    },
+    types::break_properties::{GraphemeClusterBreak as Gcb, IndicConjunctBreak as InCb},
+};
+
+/// Finds extended grapheme cluster boundaries one character at a time, as
+/// per the rules in
+/// [UAX #29, section 3.1.1](https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundary_Rules).
+///
+/// Since it only needs to see each character once, this works just as well
+/// over text which is being streamed in.
+#[derive(Clone, Debug, Default)]
+pub struct GraphemeBreaker {
+    prev: Option<Gcb>,
+    /// The number of regional indicators directly before the next character.
+    regional_indicators: usize,
+    emoji: EmojiState,
+    conjunct: ConjunctState,
+}
+
+/// How far along the text before the next character is in matching
+/// `\p{Extended_Pictographic} Extend* ZWJ`, for rule GB11.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+enum EmojiState {
+    #[default]
+    None,
+    Pictographic,
+    PictographicZwj,
+}
+
+/// How far along the text before the next character is in matching
+/// `\p{InCB=Consonant} [\p{InCB=Extend}\p{InCB=Linker}]* \p{InCB=Linker}
+/// [\p{InCB=Extend}\p{InCB=Linker}]*`, for rule GB9c.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+enum ConjunctState {
+    #[default]
+    None,
+    Consonant,
+    ConsonantLinker,
+}
+
+impl GraphemeBreaker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds the next character of the text, and returns whether there is a
+    /// cluster boundary before it. There is always a boundary before the
+    /// first character.
+    pub fn is_boundary_before(&mut self, ch: char) -> bool {
+        let cp = ch as u32;
+        let gcb = grapheme_cluster_break_of(cp);
+        let incb = indic_conjunct_break_of(cp);
+        let pictographic = EXTENDED_PICTOGRAPHIC.contains(cp);
+
+        let boundary = match self.prev {
+            // GB1
+            None => true,
+            Some(prev) => self.rules(prev, gcb, incb, pictographic),
+        };
+
+        self.regional_indicators = match gcb {
+            Gcb::RegionalIndicator => self.regional_indicators + 1,
+            _ => 0,
+        };
+
+        self.emoji = match (self.emoji, gcb) {
+            _ if pictographic => EmojiState::Pictographic,
+            (EmojiState::Pictographic, Gcb::Extend) => EmojiState::Pictographic,
+            (EmojiState::Pictographic, Gcb::Zwj) => EmojiState::PictographicZwj,
+            _ => EmojiState::None,
+        };
+
+        self.conjunct = match (self.conjunct, incb) {
+            (_, InCb::Consonant) => ConjunctState::Consonant,
+            (ConjunctState::None, _) => ConjunctState::None,
+            (_, InCb::Linker) => ConjunctState::ConsonantLinker,
+            (state, InCb::Extend) => state,
+            (_, InCb::None) => ConjunctState::None,
+        };
+
+        self.prev = Some(gcb);
+        boundary
+    }
+
+    fn rules(&self, prev: Gcb, next: Gcb, next_incb: InCb, next_pictographic: bool) -> bool {
+        match (prev, next) {
+            // GB3
+            (Gcb::Cr, Gcb::Lf) => false,
+            // GB4
+            (Gcb::Control | Gcb::Cr | Gcb::Lf, _) => true,
+            // GB5
+            (_, Gcb::Control | Gcb::Cr | Gcb::Lf) => true,
+            // GB6
+            (Gcb::L, Gcb::L | Gcb::V | Gcb::Lv | Gcb::Lvt) => false,
+            // GB7
+            (Gcb::Lv | Gcb::V, Gcb::V | Gcb::T) => false,
+            // GB8
+            (Gcb::Lvt | Gcb::T, Gcb::T) => false,
+            // GB9
+            (_, Gcb::Extend | Gcb::Zwj) => false,
+            // GB9a
+            (_, Gcb::SpacingMark) => false,
+            // GB9b
+            (Gcb::Prepend, _) => false,
+            // GB9c
+            _ if self.conjunct == ConjunctState::ConsonantLinker
+                && next_incb == InCb::Consonant =>
+            {
+                false
+            }
+            // GB11
+            (Gcb::Zwj, _) if self.emoji == EmojiState::PictographicZwj && next_pictographic => {
+                false
+            }
+            // GB12 and GB13
+            (Gcb::RegionalIndicator, Gcb::RegionalIndicator) => {
+                self.regional_indicators.is_multiple_of(2)
+            }
+            // GB999
+            _ => true,
+        }
+    }
+}
+
+/// An iterator over the extended grapheme clusters of a string, along with
+/// the byte offset each one starts at.
+pub struct Graphemes<'a> {
+    text: &'a str,
+    offset: usize,
+    breaker: GraphemeBreaker,
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.text[self.offset..];
+        let mut chars = rest.char_indices();
+        let (_, first) = chars.next()?;
+
+        // every later cluster starts with the character that the previous
+        // call found a boundary before, which the breaker has already seen
+        if self.offset == 0 {
+            self.breaker.is_boundary_before(first);
+        }
+
+        let len = chars
+            .find(|(_, ch)| self.breaker.is_boundary_before(*ch))
+            .map_or(rest.len(), |(idx, _)| idx);
+
+        let start = self.offset;
+        self.offset += len;
+
+        Some((start, &rest[..len]))
+    }
+}
+
+/// Splits the text into extended grapheme clusters.
+pub fn graphemes(text: &str) -> Graphemes<'_> {
+    Graphemes {
+        text,
+        offset: 0,
+        breaker: GraphemeBreaker::new(),
+    }
+}
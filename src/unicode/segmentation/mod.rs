@@ -0,0 +1,5 @@
+//! Text segmentation, as per [UAX #29](https://www.unicode.org/reports/tr29/).
+
+pub mod grapheme;
+pub mod sentence;
+pub mod word;
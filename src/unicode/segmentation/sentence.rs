@@ -0,0 +1,266 @@
+use nu_plugin_unicode_ucd::{
+    codegen::break_properties::sentence_break_of, types::break_properties::SentenceBreak as Sb,
+};
+
+/// Finds the byte offsets of the sentence boundaries in the text, as per the
+/// rules in
+/// [UAX #29, section 5.1](https://www.unicode.org/reports/tr29/#Sentence_Boundary_Rules).
+/// The start and end of the text are included, unless it is empty.
+pub fn sentence_boundaries(text: &str) -> Vec<usize> {
+    let mut state = BreakState::default();
+    let mut boundaries = Vec::new();
+
+    for (offset, ch) in text.char_indices() {
+        // SB1
+        if offset == 0 {
+            boundaries.push(0);
+        }
+
+        state.push(offset, sentence_break_of(ch as u32), &mut boundaries);
+    }
+
+    state.finish(&mut boundaries);
+
+    // SB2
+    if !text.is_empty() {
+        boundaries.push(text.len());
+    }
+
+    boundaries
+}
+
+fn is_ignorable(prop: Sb) -> bool {
+    matches!(prop, Sb::Extend | Sb::Format)
+}
+
+fn is_para_sep(prop: Sb) -> bool {
+    matches!(prop, Sb::Sep | Sb::Cr | Sb::Lf)
+}
+
+fn is_sa_term(prop: Sb) -> bool {
+    matches!(prop, Sb::ATerm | Sb::STerm)
+}
+
+/// Whether the property ends the lookahead of SB8, which skips over
+/// everything else.
+fn ends_lookahead(prop: Sb) -> bool {
+    matches!(prop, Sb::OLetter | Sb::Upper | Sb::Lower) || is_para_sep(prop) || is_sa_term(prop)
+}
+
+/// What the rules say about a position in the text.
+enum Decision {
+    Boundary,
+    NoBoundary,
+    /// A boundary after ATerm, unless SB8 finds Lower ahead of it, which is
+    /// only known once a character ending its lookahead is reached.
+    UnlessLowerAhead,
+}
+
+/// The state which the rules need from the text before a position, carried
+/// along as the text is read, so that each character is only looked at once.
+#[derive(Clone, Debug, Default)]
+struct BreakState {
+    /// The character before the position, for SB3 and SB4.
+    last: Option<Sb>,
+    /// The last two characters before the position, skipping over extend
+    /// and format characters as per SB5.
+    prev: Option<Sb>,
+    prev2: Option<Sb>,
+    /// The SATerm which starts the characters before the position, if they
+    /// are SATerm Close* Sp*.
+    term: Option<Sb>,
+    /// Whether there are any spaces after that SATerm.
+    spaces: bool,
+    /// The byte offset of the position which is waiting on the SB8
+    /// lookahead. No position after it can be a boundary until that is
+    /// resolved, so there is never more than one.
+    pending: Option<usize>,
+}
+
+impl BreakState {
+    /// Reads the next character, which is at the given byte offset, and adds
+    /// the boundaries which are now known to be there to `boundaries`.
+    fn push(&mut self, offset: usize, next: Sb, boundaries: &mut Vec<usize>) {
+        if ends_lookahead(next)
+            && let Some(pending) = self.pending.take()
+            && next != Sb::Lower
+        {
+            boundaries.push(pending);
+        }
+
+        match self.decide(next) {
+            Decision::Boundary => boundaries.push(offset),
+            Decision::NoBoundary => {}
+            Decision::UnlessLowerAhead => self.pending = Some(offset),
+        }
+
+        self.last = Some(next);
+
+        if is_ignorable(next) {
+            return;
+        }
+
+        self.prev2 = self.prev.replace(next);
+
+        match next {
+            prop if is_sa_term(prop) => {
+                self.term = Some(prop);
+                self.spaces = false;
+            }
+            Sb::Close if !self.spaces => {}
+            Sb::Sp if self.term.is_some() => self.spaces = true,
+            _ => {
+                self.term = None;
+                self.spaces = false;
+            }
+        }
+    }
+
+    /// Adds the boundary waiting on the SB8 lookahead, if any, once the end
+    /// of the text has been reached without finding Lower.
+    fn finish(&mut self, boundaries: &mut Vec<usize>) {
+        boundaries.extend(self.pending.take());
+    }
+
+    /// Applies the rules to the position before the next character. SB1 and
+    /// SB2 are left to the caller.
+    fn decide(&self, next: Sb) -> Decision {
+        let Some(last) = self.last else {
+            return Decision::NoBoundary;
+        };
+
+        match (last, next) {
+            // SB3
+            (Sb::Cr, Sb::Lf) => return Decision::NoBoundary,
+            // SB4
+            (p, _) if is_para_sep(p) => return Decision::Boundary,
+            // SB5
+            (_, Sb::Extend | Sb::Format) => return Decision::NoBoundary,
+            _ => {}
+        }
+
+        // SB5: from here on, the rules skip over any extend and format
+        // characters, which stick to the character before them
+        match (self.prev, next) {
+            // SB6
+            (Some(Sb::ATerm), Sb::Numeric) => return Decision::NoBoundary,
+            // SB7
+            (Some(Sb::ATerm), Sb::Upper) if matches!(self.prev2, Some(Sb::Upper | Sb::Lower)) => {
+                return Decision::NoBoundary;
+            }
+            _ => {}
+        }
+
+        // the rest of the rules only apply after SATerm Close* Sp*
+        let Some(term) = self.term else {
+            // SB998
+            return Decision::NoBoundary;
+        };
+
+        let boundary = match next {
+            // SB8a
+            Sb::SContinue | Sb::ATerm | Sb::STerm => false,
+            // SB9
+            Sb::Close if !self.spaces => false,
+            // SB10
+            Sb::Sp | Sb::Sep | Sb::Cr | Sb::Lf => false,
+            // SB11
+            _ => true,
+        };
+
+        match term {
+            // SB8
+            Sb::ATerm if next == Sb::Lower => Decision::NoBoundary,
+            Sb::ATerm if boundary && !ends_lookahead(next) => Decision::UnlessLowerAhead,
+            _ if boundary => Decision::Boundary,
+            _ => Decision::NoBoundary,
+        }
+    }
+}
+
+/// Splits text which arrives in chunks into sentences which won't change
+/// when more text arrives.
+///
+/// Each character is read once, as it arrives, and the text is split at the
+/// last boundary which is known to be there. Only the sentence after that
+/// is held back.
+#[derive(Clone, Debug, Default)]
+pub struct SentenceSplitter {
+    /// The text after the last split, which starts at a boundary.
+    carry: String,
+    /// The state of the rules at the end of `carry`, with offsets into it.
+    state: BreakState,
+    boundaries: Vec<usize>,
+}
+
+impl SentenceSplitter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends the next chunk of text, and returns the text before the last
+    /// boundary which is now settled, which may be empty.
+    pub fn push(&mut self, chunk: &str) -> String {
+        let start = self.carry.len();
+        self.carry.push_str(chunk);
+
+        for (offset, ch) in chunk.char_indices() {
+            self.state.push(
+                start + offset,
+                sentence_break_of(ch as u32),
+                &mut self.boundaries,
+            );
+        }
+
+        let Some(split) = self.boundaries.pop() else {
+            return String::new();
+        };
+
+        self.boundaries.clear();
+        self.state.pending = self.state.pending.map(|pending| pending - split);
+
+        let rest = self.carry.split_off(split);
+        std::mem::replace(&mut self.carry, rest)
+    }
+
+    /// Returns the rest of the text, once there is no more to come.
+    pub fn finish(&mut self) -> String {
+        self.state = BreakState::default();
+        self.boundaries.clear();
+
+        std::mem::take(&mut self.carry)
+    }
+}
+
+/// An iterator over the sentences of a string, along with the byte offset
+/// each one starts at.
+pub struct Sentences<'a> {
+    text: &'a str,
+    boundaries: std::vec::IntoIter<usize>,
+    start: usize,
+}
+
+impl<'a> Iterator for Sentences<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let end = self.boundaries.next()?;
+        let start = std::mem::replace(&mut self.start, end);
+
+        Some((start, &self.text[start..end]))
+    }
+}
+
+/// Splits the text into sentences. Trailing whitespace and line breaks are
+/// part of the sentence before them.
+pub fn sentences(text: &str) -> Sentences<'_> {
+    let mut boundaries = sentence_boundaries(text).into_iter();
+    // the first boundary is the start of the text
+    boundaries.next();
+
+    Sentences {
+        text,
+        boundaries,
+        start: 0,
+    }
+}
@@ -0,0 +1,187 @@
+use nu_plugin_unicode_ucd::{
+    codegen::{
+        break_properties::word_break_of, emoji_properties::EXTENDED_PICTOGRAPHIC,
+        unicode_data::general_category_of,
+    },
+    types::break_properties::WordBreak as Wb,
+};
+
+/// Finds the byte offsets of the word boundaries in the text, as per the
+/// rules in
+/// [UAX #29, section 4.1.1](https://www.unicode.org/reports/tr29/#Word_Boundary_Rules).
+/// The start and end of the text are included, unless it is empty.
+///
+/// Some of the rules look ahead by two characters, so unlike grapheme
+/// clusters, this works on the whole text at once.
+pub fn word_boundaries(text: &str) -> Vec<usize> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let props: Vec<Wb> = chars
+        .iter()
+        .map(|(_, ch)| word_break_of(*ch as u32))
+        .collect();
+    let mut boundaries = Vec::new();
+    // the number of regional indicators directly before the next character,
+    // skipping over extend and format characters as per WB4
+    let mut regional_indicators = 0_usize;
+
+    for i in 0..=chars.len() {
+        // WB1 and WB2
+        let boundary =
+            i == 0 || i == chars.len() || is_boundary(&chars, &props, i, regional_indicators);
+
+        if boundary && !chars.is_empty() {
+            boundaries.push(chars.get(i).map_or(text.len(), |(offset, _)| *offset));
+        }
+
+        match props.get(i) {
+            Some(Wb::RegionalIndicator) => regional_indicators += 1,
+            Some(prop) if is_ignorable(*prop) => {}
+            _ => regional_indicators = 0,
+        }
+    }
+
+    boundaries
+}
+
+fn is_ignorable(prop: Wb) -> bool {
+    matches!(prop, Wb::Extend | Wb::Format | Wb::Zwj)
+}
+
+fn is_ah_letter(prop: Wb) -> bool {
+    matches!(prop, Wb::ALetter | Wb::HebrewLetter)
+}
+
+fn is_mid_num_let_q(prop: Wb) -> bool {
+    matches!(prop, Wb::MidNumLet | Wb::SingleQuote)
+}
+
+/// Whether there is a word boundary between the characters at `i - 1` and
+/// `i`, given the number of regional indicators directly before `i`.
+fn is_boundary(
+    chars: &[(usize, char)],
+    props: &[Wb],
+    i: usize,
+    regional_indicators: usize,
+) -> bool {
+    let (prev, next) = (props[i - 1], props[i]);
+
+    match (prev, next) {
+        // WB3
+        (Wb::Cr, Wb::Lf) => return false,
+        // WB3a
+        (Wb::Newline | Wb::Cr | Wb::Lf, _) => return true,
+        // WB3b
+        (_, Wb::Newline | Wb::Cr | Wb::Lf) => return true,
+        // WB3c
+        (Wb::Zwj, _) if EXTENDED_PICTOGRAPHIC.contains(chars[i].1 as u32) => return false,
+        // WB3d
+        (Wb::WSegSpace, Wb::WSegSpace) => return false,
+        // WB4
+        (_, Wb::Extend | Wb::Format | Wb::Zwj) => return false,
+        _ => {}
+    }
+
+    // WB4: from here on, the rules skip over any extend and format
+    // characters, which stick to the character before them
+    let before = |idx: usize| {
+        (0..idx)
+            .rev()
+            .find(|j| !is_ignorable(props[*j]))
+            .or(idx.checked_sub(1))
+    };
+    let after = |idx: usize| (idx + 1..props.len()).find(|j| !is_ignorable(props[*j]));
+
+    let Some(prev_idx) = before(i) else {
+        return true;
+    };
+    let prev = props[prev_idx];
+    let prev2 = before(prev_idx).filter(|j| *j < prev_idx).map(|j| props[j]);
+    let next2 = after(i).map(|j| props[j]);
+
+    match (prev, next) {
+        // WB5
+        (p, n) if is_ah_letter(p) && is_ah_letter(n) => false,
+        // WB6
+        (p, Wb::MidLetter) | (p, Wb::MidNumLet | Wb::SingleQuote)
+            if is_ah_letter(p) && next2.is_some_and(is_ah_letter) =>
+        {
+            false
+        }
+        // WB7
+        (Wb::MidLetter, n) | (Wb::MidNumLet | Wb::SingleQuote, n)
+            if is_ah_letter(n) && prev2.is_some_and(is_ah_letter) =>
+        {
+            false
+        }
+        // WB7a
+        (Wb::HebrewLetter, Wb::SingleQuote) => false,
+        // WB7b
+        (Wb::HebrewLetter, Wb::DoubleQuote) if next2 == Some(Wb::HebrewLetter) => false,
+        // WB7c
+        (Wb::DoubleQuote, Wb::HebrewLetter) if prev2 == Some(Wb::HebrewLetter) => false,
+        // WB8, WB9 and WB10
+        (Wb::Numeric, Wb::Numeric) => false,
+        (p, Wb::Numeric) if is_ah_letter(p) => false,
+        (Wb::Numeric, n) if is_ah_letter(n) => false,
+        // WB11
+        (Wb::MidNum, Wb::Numeric) if prev2 == Some(Wb::Numeric) => false,
+        (p, Wb::Numeric) if is_mid_num_let_q(p) && prev2 == Some(Wb::Numeric) => false,
+        // WB12
+        (Wb::Numeric, Wb::MidNum) if next2 == Some(Wb::Numeric) => false,
+        (Wb::Numeric, n) if is_mid_num_let_q(n) && next2 == Some(Wb::Numeric) => false,
+        // WB13
+        (Wb::Katakana, Wb::Katakana) => false,
+        // WB13a
+        (
+            Wb::ALetter | Wb::HebrewLetter | Wb::Numeric | Wb::Katakana | Wb::ExtendNumLet,
+            Wb::ExtendNumLet,
+        ) => false,
+        // WB13b
+        (Wb::ExtendNumLet, Wb::ALetter | Wb::HebrewLetter | Wb::Numeric | Wb::Katakana) => false,
+        // WB15 and WB16
+        (Wb::RegionalIndicator, Wb::RegionalIndicator) => regional_indicators.is_multiple_of(2),
+        // WB999
+        _ => true,
+    }
+}
+
+/// An iterator over the word segments of a string, along with the byte
+/// offset each one starts at. Everything between two boundaries is a
+/// segment, including whitespace and punctuation.
+pub struct Words<'a> {
+    text: &'a str,
+    boundaries: std::vec::IntoIter<usize>,
+    start: usize,
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let end = self.boundaries.next()?;
+        let start = std::mem::replace(&mut self.start, end);
+
+        Some((start, &self.text[start..end]))
+    }
+}
+
+/// Splits the text into word segments.
+pub fn words(text: &str) -> Words<'_> {
+    let mut boundaries = word_boundaries(text).into_iter();
+    // the first boundary is the start of the text
+    boundaries.next();
+
+    Words {
+        text,
+        boundaries,
+        start: 0,
+    }
+}
+
+/// Whether the segment is a word, rather than whitespace, punctuation or
+/// symbols, i.e. whether it has any letters or numbers in it.
+pub fn is_word(segment: &str) -> bool {
+    segment
+        .chars()
+        .any(|ch| matches!(general_category_of(ch as u32).as_bytes()[0], b'L' | b'N'))
+}
@@ -0,0 +1,82 @@
+use nu_plugin_unicode_ucd::{
+    codegen::{
+        break_properties::grapheme_cluster_break_of,
+        east_asian_widths::east_asian_width_of,
+        emoji_properties::{EMOJI, EMOJI_PRESENTATION},
+        unicode_data::general_category_of,
+    },
+    types::break_properties::GraphemeClusterBreak,
+};
+
+use crate::unicode::segmentation::grapheme::graphemes;
+
+const TEXT_PRESENTATION_SELECTOR: char = '\u{FE0E}';
+const EMOJI_PRESENTATION_SELECTOR: char = '\u{FE0F}';
+
+/// Computes the number of terminal columns the text takes up, as the sum of
+/// the widths of its extended grapheme clusters.
+///
+/// If `ambiguous_wide` is set, characters with an East_Asian_Width of
+/// Ambiguous take up two columns, as they do in CJK locales.
+pub fn str_width(text: &str, ambiguous_wide: bool) -> usize {
+    graphemes(text)
+        .map(|(_, cluster)| cluster_width(cluster, ambiguous_wide))
+        .sum()
+}
+
+/// Computes the number of terminal columns a single extended grapheme
+/// cluster takes up.
+///
+/// The cluster is as wide as its base, which is the first character with a
+/// width of its own. That is usually the first character of the cluster, but
+/// prepended characters such as U+0600 ARABIC NUMBER SIGN come before it.
+/// A pair of regional indicators, which renders as a flag, is two columns
+/// wide.
+pub fn cluster_width(cluster: &str, ambiguous_wide: bool) -> usize {
+    let Some(first) = cluster.chars().next() else {
+        return 0;
+    };
+
+    if grapheme_cluster_break_of(first as u32) == GraphemeClusterBreak::RegionalIndicator {
+        return if cluster.chars().nth(1).is_some() {
+            2
+        } else {
+            1
+        };
+    }
+
+    cluster
+        .chars()
+        .map(|ch| char_width(ch, cluster, ambiguous_wide))
+        .find(|width| *width > 0)
+        .unwrap_or(0)
+}
+
+/// Computes the number of terminal columns a character of the cluster takes
+/// up on its own. Emoji presentation makes an emoji two columns wide, and
+/// characters which combine with or modify the text around them take up no
+/// columns.
+fn char_width(ch: char, cluster: &str, ambiguous_wide: bool) -> usize {
+    let cp = ch as u32;
+
+    if EMOJI.contains(cp) {
+        if cluster.contains(EMOJI_PRESENTATION_SELECTOR) {
+            return 2;
+        }
+
+        if !cluster.contains(TEXT_PRESENTATION_SELECTOR) && EMOJI_PRESENTATION.contains(cp) {
+            return 2;
+        }
+    }
+
+    match general_category_of(cp) {
+        "Mn" | "Me" | "Cf" | "Cc" | "Zl" | "Zp" | "Cs" => return 0,
+        _ => {}
+    }
+
+    match east_asian_width_of(cp) {
+        "W" | "F" => 2,
+        "A" if ambiguous_wide => 2,
+        _ => 1,
+    }
+}
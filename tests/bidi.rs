@@ -0,0 +1,138 @@
+//! Tests for the bidirectional algorithm, with the embedding levels and the
+//! visual order of each case worked out by hand from the rules in
+//! [UAX #9](https://www.unicode.org/reports/tr9/), written the way the
+//! cases in `BidiCharacterTest.txt` are.
+
+use nu_plugin_unicode::unicode::bidi::{Direction, paragraphs};
+
+struct TestCase {
+    /// What the case checks.
+    rule: &'static str,
+    text: String,
+    direction: Direction,
+    /// The resolved paragraph level.
+    level: u8,
+    levels: Vec<u8>,
+    visual: &'static str,
+}
+
+fn test_cases() -> Vec<TestCase> {
+    vec![
+        TestCase {
+            rule: "N0: brackets take the direction of the strong text before them",
+            text: "א (ב) c".into(),
+            direction: Direction::Ltr,
+            level: 0,
+            levels: vec![1, 1, 1, 1, 1, 0, 0],
+            visual: "(ב) א c",
+        },
+        TestCase {
+            rule: "W2: numbers after Arabic letters are Arabic numbers",
+            text: "ا1%".into(),
+            direction: Direction::Auto,
+            level: 1,
+            levels: vec![1, 2, 1],
+            visual: "%1ا",
+        },
+        TestCase {
+            rule: "W5: terminators after European numbers are European numbers",
+            text: "א1%".into(),
+            direction: Direction::Auto,
+            level: 1,
+            levels: vec![1, 2, 2],
+            visual: "1%א",
+        },
+        TestCase {
+            rule: "X5a: isolates are resolved separately from the text around them",
+            text: "a\u{2067}בג\u{2069}d".into(),
+            direction: Direction::Ltr,
+            level: 0,
+            levels: vec![0, 0, 1, 1, 0, 0],
+            visual: "a\u{2067}גב\u{2069}d",
+        },
+        TestCase {
+            rule: "P2 and X5c: isolates are skipped when detecting the direction",
+            text: "\u{2068}אb\u{2069}c".into(),
+            direction: Direction::Auto,
+            level: 0,
+            levels: vec![0, 1, 2, 0, 0],
+            visual: "\u{2068}bא\u{2069}c",
+        },
+        TestCase {
+            rule: "X5 and X7: embeddings past the deepest level are counted and ignored",
+            text: format!("{}א\u{202C}ב", "\u{202B}".repeat(64)),
+            direction: Direction::Ltr,
+            level: 0,
+            levels: [vec![0; 64], vec![125, 125, 125]].concat(),
+            visual: "בא",
+        },
+        TestCase {
+            rule: "L1: whitespace at the end of the line is reset to the paragraph level",
+            text: "\u{202B}א  ".into(),
+            direction: Direction::Ltr,
+            level: 0,
+            levels: vec![0, 1, 0, 0],
+            visual: "א  ",
+        },
+        TestCase {
+            rule: "L1: segment separators and whitespace before them are reset",
+            text: "ab \tcd".into(),
+            direction: Direction::Rtl,
+            level: 1,
+            levels: vec![2, 2, 1, 1, 2, 2],
+            visual: "cd\t ab",
+        },
+    ]
+}
+
+#[test]
+fn bidi_test_cases() {
+    let mut failures = Vec::new();
+
+    for case in test_cases() {
+        let found = paragraphs(&case.text, case.direction);
+        let [paragraph] = found.as_slice() else {
+            failures.push(format!("{}: {} paragraphs", case.rule, found.len()));
+            continue;
+        };
+
+        let actual = (paragraph.level, &paragraph.levels, paragraph.visual());
+        let expected = (case.level, &case.levels, case.visual.to_owned());
+
+        if actual != expected {
+            failures.push(format!(
+                "{}: resolved {actual:?}, expected {expected:?}",
+                case.rule
+            ));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} failures:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+fn paragraph_texts(text: &str) -> Vec<&str> {
+    paragraphs(text, Direction::Auto)
+        .into_iter()
+        .map(|paragraph| &text[paragraph.range])
+        .collect()
+}
+
+#[test]
+fn paragraphs_are_split_at_separators() {
+    assert_eq!(paragraph_texts("a\nb\u{2029}c"), ["a", "b", "c"]);
+    assert_eq!(paragraph_texts("a\n\nb"), ["a", "", "b"]);
+    assert_eq!(paragraph_texts("a\n"), ["a"]);
+    assert_eq!(paragraph_texts(""), [""]);
+}
+
+#[test]
+fn crlf_is_one_paragraph_separator() {
+    assert_eq!(paragraph_texts("a\r\nb"), ["a", "b"]);
+    assert_eq!(paragraph_texts("a\r\rb"), ["a", "", "b"]);
+    assert_eq!(paragraph_texts("a\n\r\nb"), ["a", "", "b"]);
+}
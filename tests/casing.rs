@@ -0,0 +1,64 @@
+//! Tests for the full case mappings and case folding, with the mappings
+//! taken from `SpecialCasing.txt` and `CaseFolding.txt`.
+
+use nu_plugin_unicode::unicode::casing::{
+    casefold, language_of, to_lowercase, to_titlecase, to_uppercase,
+};
+
+#[test]
+fn full_mappings() {
+    assert_eq!(to_uppercase("straße", ""), "STRASSE");
+    assert_eq!(to_uppercase("\u{FB01}x", ""), "FIX");
+    assert_eq!(to_lowercase("İ", ""), "i\u{307}");
+}
+
+#[test]
+fn final_sigma() {
+    assert_eq!(to_lowercase("ΟΔΟΣ ΟΔΟΣ.", ""), "οδος οδος.");
+    assert_eq!(to_lowercase("ΟΔΟΣΑ", ""), "οδοσα");
+    // there is no cased letter before it
+    assert_eq!(to_lowercase("Σ", ""), "σ");
+}
+
+#[test]
+fn turkic_and_azeri() {
+    assert_eq!(to_uppercase("i", "tr"), "İ");
+    assert_eq!(to_lowercase("I", "tr"), "ı");
+    assert_eq!(to_lowercase("I", "az"), "ı");
+    // After_I: the dot above is dropped, since the i already has one
+    assert_eq!(to_lowercase("I\u{307}", "tr"), "i");
+    assert_eq!(to_lowercase("I", ""), "i");
+}
+
+#[test]
+fn lithuanian() {
+    // More_Above: the dot is kept when there are accents above
+    assert_eq!(to_lowercase("I\u{300}", "lt"), "i\u{307}\u{300}");
+    assert_eq!(to_lowercase("I", "lt"), "i");
+    // After_Soft_Dotted: the dot is dropped when uppercasing
+    assert_eq!(to_uppercase("i\u{307}", "lt"), "I");
+}
+
+#[test]
+fn titlecase() {
+    assert_eq!(to_titlecase("hello wORLD", ""), "Hello World");
+    assert_eq!(to_titlecase("ǆemal", ""), "ǅemal");
+    assert_eq!(to_titlecase("ßa", ""), "Ssa");
+    assert_eq!(to_titlecase("istanbul", "tr"), "İstanbul");
+}
+
+#[test]
+fn folding() {
+    assert_eq!(casefold("Straße", false, false), "strasse");
+    assert_eq!(casefold("Straße", true, false), "straße");
+    assert_eq!(casefold("İ", false, false), "i\u{307}");
+    assert_eq!(casefold("I", false, true), "ı");
+    assert_eq!(casefold("İ", false, true), "i");
+}
+
+#[test]
+fn languages_of_locales() {
+    assert_eq!(language_of("tr-CY"), "tr");
+    assert_eq!(language_of("LT_lt"), "lt");
+    assert_eq!(language_of(""), "");
+}
@@ -0,0 +1,206 @@
+//! Tests for how `unicode chars` decodes bytes: where each code point came
+//! from, and what happens to invalid byte sequences. Inputs are made long
+//! enough for code points and invalid sequences to straddle the boundaries
+//! of the chunks they are read in.
+
+use nu_plugin_test_support::PluginTest;
+use nu_plugin_unicode::Unicode;
+use nu_protocol::{ByteStream, PipelineData, Record, Signals, Span, Value};
+
+/// The size of the chunks that bytes are read in.
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// Runs the command on the bytes, passed either as a binary value or as a
+/// byte stream, and returns the rows of the output. An error from the
+/// command as a whole is returned as the only row.
+fn chars(command: &str, input: &[u8], stream: bool) -> Vec<Value> {
+    let span = Span::test_data();
+    let data = if stream {
+        PipelineData::ByteStream(
+            ByteStream::read_binary(input.to_vec(), span, Signals::empty()),
+            None,
+        )
+    } else {
+        PipelineData::Value(Value::binary(input, span), None)
+    };
+
+    let mut plugin_test = PluginTest::new("unicode", Unicode.into()).unwrap();
+
+    match plugin_test.eval_with(command, data) {
+        Ok(PipelineData::Value(Value::List { vals, .. }, _)) => vals,
+        Ok(data) => data.into_iter().collect(),
+        Err(err) => vec![Value::error(err, span)],
+    }
+}
+
+fn int(record: &Record, column: &str) -> usize {
+    record.get(column).unwrap().as_int().unwrap() as usize
+}
+
+fn bytes<'a>(record: &'a Record, column: &str) -> &'a [u8] {
+    record.get(column).unwrap().as_binary().unwrap()
+}
+
+/// Checks that the records account for every byte of the input after the
+/// BOM, in order, and decode to the text.
+fn check_sources(records: &[Value], input: &[u8], bom_len: usize, text: &str) {
+    let mut offset = bom_len;
+    let mut decoded = String::new();
+
+    for (idx, record) in records.iter().enumerate() {
+        let record = record.as_record().unwrap();
+        let source = bytes(record, "bytes");
+
+        assert_eq!(int(record, "char_index"), idx);
+        assert_eq!(int(record, "byte_offset"), offset, "char {idx}");
+        assert_eq!(int(record, "byte_length"), source.len(), "char {idx}");
+        assert_eq!(source, &input[offset..offset + source.len()], "char {idx}");
+
+        offset += source.len();
+        decoded.push(char::from_u32(int(record, "codepoint") as u32).unwrap());
+    }
+
+    assert_eq!(offset, input.len());
+    assert_eq!(decoded, text);
+}
+
+#[test]
+fn sources_of_utf8_across_chunks() {
+    // every multi-byte character straddles a chunk boundary somewhere
+    let text = "aé漢😀".repeat(CHUNK_SIZE);
+
+    for stream in [true, false] {
+        let records = chars("unicode chars", text.as_bytes(), stream);
+        check_sources(&records, text.as_bytes(), 0, &text);
+    }
+}
+
+#[test]
+fn sources_of_utf16_surrogate_pairs() {
+    let text = format!("{}😀x", "a".repeat(CHUNK_SIZE / 2 - 2));
+    let mut input = vec![0xFF, 0xFE];
+    input.extend(text.encode_utf16().flat_map(u16::to_le_bytes));
+
+    for stream in [true, false] {
+        let records = chars("unicode chars", &input, stream);
+        check_sources(&records, &input, 2, &text);
+
+        // the surrogate pair starts two bytes before the end of the chunk
+        let emoji = records[CHUNK_SIZE / 2 - 2].as_record().unwrap();
+        assert_eq!(int(emoji, "byte_offset"), CHUNK_SIZE - 2);
+        assert_eq!(bytes(emoji, "bytes"), [0x3D, 0xD8, 0x00, 0xDE]);
+    }
+}
+
+#[test]
+fn sources_of_shift_jis_across_chunks() {
+    let text = format!("{}漢あｱx", "a".repeat(CHUNK_SIZE - 1));
+    let (input, _, _) = encoding_rs::SHIFT_JIS.encode(&text);
+
+    for stream in [true, false] {
+        let records = chars("unicode chars --encoding shift_jis", &input, stream);
+        check_sources(&records, &input, 0, &text);
+
+        let kanji = records[CHUNK_SIZE - 1].as_record().unwrap();
+        assert_eq!(int(kanji, "byte_offset"), CHUNK_SIZE - 1);
+        assert_eq!(bytes(kanji, "bytes"), [0x8A, 0xBF]);
+    }
+}
+
+#[test]
+fn invalid_bytes_across_chunks_are_reported() {
+    // a truncated three byte sequence, which straddles the chunk boundary
+    let mut input = vec![b'a'; CHUNK_SIZE - 1];
+    input.extend(b"\xE2\x82x");
+
+    for stream in [true, false] {
+        let records = chars("unicode chars --report-invalid", &input, stream);
+        assert_eq!(records.len(), CHUNK_SIZE + 1);
+
+        let valid = records[0].as_record().unwrap();
+        let invalid = records[CHUNK_SIZE - 1].as_record().unwrap();
+        let after = records[CHUNK_SIZE].as_record().unwrap();
+
+        assert_eq!(valid.get("invalid"), Some(&Value::test_bool(false)));
+        assert_eq!(invalid.get("invalid"), Some(&Value::test_bool(true)));
+        assert_eq!(after.get("invalid"), Some(&Value::test_bool(false)));
+
+        assert_eq!(int(invalid, "char_index"), CHUNK_SIZE - 1);
+        assert_eq!(int(invalid, "byte_offset"), CHUNK_SIZE - 1);
+        assert_eq!(int(invalid, "byte_length"), 2);
+        assert_eq!(bytes(invalid, "bytes"), b"\xE2\x82");
+
+        assert_eq!(int(after, "char_index"), CHUNK_SIZE);
+        assert_eq!(int(after, "byte_offset"), CHUNK_SIZE + 1);
+
+        // the invalid record has the columns of the others, left empty
+        assert_eq!(
+            invalid.columns().collect::<Vec<_>>(),
+            valid.columns().collect::<Vec<_>>()
+        );
+
+        for (column, val) in invalid.iter().skip(4) {
+            if column != "invalid" {
+                assert!(val.is_nothing(), "{column} is {val:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn strict_fails_after_the_valid_chars() {
+    let mut input = vec![b'a'; CHUNK_SIZE + 8];
+    input.extend(b"\xFFb");
+
+    let records = chars("unicode chars --strict", &input, true);
+    let (error, valid) = records.split_last().unwrap();
+
+    assert_eq!(valid.len(), CHUNK_SIZE + 8);
+    assert!(valid.iter().all(|val| val.as_record().is_ok()));
+
+    let Value::Error { error, .. } = error else {
+        panic!("expected an error, got {error:?}");
+    };
+    assert!(
+        error.to_string().contains("invalid byte sequence"),
+        "{error:?}"
+    );
+
+    // a binary value is decoded as a whole, so it fails as a whole
+    let records = chars("unicode chars --strict", &input, false);
+    assert!(matches!(records.as_slice(), [Value::Error { .. }]));
+}
+
+#[test]
+fn sources_of_unassigned_code_points() {
+    let text = "a\u{378}b";
+    let mut plugin_test = PluginTest::new("unicode", Unicode.into()).unwrap();
+    let from_string = plugin_test
+        .eval(r#""a\u{378}b" | unicode chars"#)
+        .unwrap()
+        .into_value(Span::test_data())
+        .unwrap()
+        .into_list()
+        .unwrap();
+
+    for records in [
+        from_string,
+        chars("unicode chars", text.as_bytes(), true),
+        chars("unicode chars", text.as_bytes(), false),
+    ] {
+        check_sources(&records, text.as_bytes(), 0, text);
+
+        // the unassigned code point has the columns of the others, left
+        // empty apart from the codepoint and where it came from
+        let assigned = records[0].as_record().unwrap();
+        let unassigned = records[1].as_record().unwrap();
+
+        assert_eq!(
+            unassigned.columns().collect::<Vec<_>>(),
+            assigned.columns().collect::<Vec<_>>()
+        );
+        assert!(unassigned.get("name").unwrap().is_nothing());
+        assert_eq!(int(unassigned, "byte_offset"), 1);
+        assert_eq!(bytes(unassigned, "bytes"), "\u{378}".as_bytes());
+    }
+}
@@ -0,0 +1,89 @@
+//! Tests for comparing strings under each equivalence, and for where they
+//! are reported to differ.
+
+use nu_plugin_unicode::unicode::comparison::{Comparison, Equivalence, compare};
+
+fn equal(left: &str, right: &str, equivalence: Equivalence) -> bool {
+    compare(left, right, equivalence).equal
+}
+
+fn mismatch(left: &str, right: &str, equivalence: Equivalence) -> Option<(usize, usize)> {
+    compare(left, right, equivalence).mismatch
+}
+
+#[test]
+fn exact() {
+    assert!(equal("école", "école", Equivalence::Exact));
+    assert!(!equal("é", "e\u{301}", Equivalence::Exact));
+}
+
+#[test]
+fn canonical() {
+    assert!(equal("é", "e\u{301}", Equivalence::Canonical));
+    assert!(equal(
+        "q\u{307}\u{323}",
+        "q\u{323}\u{307}",
+        Equivalence::Canonical
+    ));
+    assert!(!equal("\u{FB01}", "fi", Equivalence::Canonical));
+}
+
+#[test]
+fn compatibility() {
+    assert!(equal("\u{FB01}", "fi", Equivalence::Compatibility));
+    assert!(equal("x²", "x2", Equivalence::Compatibility));
+    assert!(!equal("A", "a", Equivalence::Compatibility));
+}
+
+#[test]
+fn caseless() {
+    assert!(equal("Straße", "STRASSE", Equivalence::Caseless));
+    // accented names match whether or not their accents are precomposed
+    assert!(equal("é", "e\u{301}", Equivalence::Caseless));
+    assert!(equal("ÉCOLE", "e\u{301}cole", Equivalence::Caseless));
+    assert!(equal("Å", "a\u{30A}", Equivalence::Caseless));
+    // U+0345 folds to ι, which only matches once it has been reordered
+    assert!(equal("\u{1FB3}", "α\u{345}", Equivalence::Caseless));
+    assert!(!equal("école", "ecole", Equivalence::Caseless));
+    assert!(!equal("x²", "x2", Equivalence::Caseless));
+}
+
+#[test]
+fn identifier() {
+    assert!(equal("ＡＢＣ", "abc", Equivalence::Identifier));
+    assert!(equal("\u{FB01}", "FI", Equivalence::Identifier));
+    assert!(equal("ÉCOLE", "e\u{301}cole", Equivalence::Identifier));
+    // default ignorable code points are dropped
+    assert!(equal("a\u{AD}b", "ab", Equivalence::Identifier));
+    assert!(!equal("école", "ecole", Equivalence::Identifier));
+}
+
+#[test]
+fn first_difference() {
+    assert_eq!(
+        compare("Noël", "NOËL", Equivalence::Caseless),
+        Comparison {
+            equal: true,
+            mismatch: None,
+        }
+    );
+    assert_eq!(
+        mismatch("école", "ecole", Equivalence::Caseless),
+        Some((0, 0))
+    );
+    // the cluster which differs is reported as a whole
+    assert_eq!(
+        mismatch("abe\u{301}", "abe\u{300}", Equivalence::Exact),
+        Some((2, 2))
+    );
+    assert_eq!(
+        mismatch(
+            "Café au lait",
+            "CAFE\u{301} AU LAIT!",
+            Equivalence::Caseless
+        ),
+        Some((13, 14))
+    );
+    // one string is a prefix of the other
+    assert_eq!(mismatch("abc", "abcd", Equivalence::Exact), Some((3, 3)));
+}
@@ -0,0 +1,252 @@
+//! Conformance tests for normalization, driven by `NormalizationTest.txt`
+//! from the UCD.
+
+use std::collections::HashSet;
+
+use nu_plugin_unicode::unicode::normalization::{
+    NormalizationForm, Normalizer, is_normalized, nfkc_casefold, normalize,
+};
+use nu_plugin_unicode_ucd::codegen::binary_properties::binary_properties;
+
+const FORMS: [NormalizationForm; 4] = [
+    NormalizationForm::Nfc,
+    NormalizationForm::Nfd,
+    NormalizationForm::Nfkc,
+    NormalizationForm::Nfkd,
+];
+
+struct TestCase {
+    part: String,
+    line: usize,
+    /// The five columns of the test, c1 to c5.
+    columns: Vec<String>,
+}
+
+fn parse_codepoints(field: &str) -> String {
+    field
+        .split_whitespace()
+        .map(|cp| char::from_u32(u32::from_str_radix(cp, 16).unwrap()).unwrap())
+        .collect()
+}
+
+fn test_cases() -> Vec<TestCase> {
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/crates/nu_plugin_unicode_ucd/ucd/NormalizationTest.txt"
+    );
+    let mut part = String::new();
+    let mut cases = Vec::new();
+
+    for (idx, line) in std::fs::read_to_string(path).unwrap().lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('@') {
+            part = name.trim().to_owned();
+            continue;
+        }
+
+        cases.push(TestCase {
+            part: part.clone(),
+            line: idx + 1,
+            columns: line.split(';').take(5).map(parse_codepoints).collect(),
+        });
+    }
+
+    cases
+}
+
+/// The column each column must normalize to in the given form, as listed in
+/// the header of `NormalizationTest.txt`.
+fn expected_columns(form: NormalizationForm) -> [usize; 5] {
+    match form {
+        NormalizationForm::Nfc => [1, 1, 1, 3, 3],
+        NormalizationForm::Nfd => [2, 2, 2, 4, 4],
+        NormalizationForm::Nfkc => [3, 3, 3, 3, 3],
+        NormalizationForm::Nfkd => [4, 4, 4, 4, 4],
+    }
+}
+
+fn normalize_streamed(text: &str, form: NormalizationForm) -> String {
+    let mut normalizer = Normalizer::new(form);
+    let mut normalized = String::new();
+
+    for ch in text.chars() {
+        normalized.push_str(&normalizer.push(ch.encode_utf8(&mut [0; 4])));
+    }
+
+    normalized.push_str(&normalizer.finish());
+    normalized
+}
+
+fn codepoints(text: &str) -> String {
+    text.chars()
+        .map(|ch| format!("{:04X}", ch as u32))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn check_conformance(normalize: impl Fn(&str, NormalizationForm) -> String) {
+    let mut failures = Vec::new();
+
+    for case in test_cases() {
+        for form in FORMS {
+            for (column, expected) in expected_columns(form).into_iter().enumerate() {
+                let actual = normalize(&case.columns[column], form);
+
+                if actual != case.columns[expected] {
+                    failures.push(format!(
+                        "line {} ({}): {form:?} of c{} is [{}], expected c{} [{}]",
+                        case.line,
+                        case.part,
+                        column + 1,
+                        codepoints(&actual),
+                        expected + 1,
+                        codepoints(&case.columns[expected]),
+                    ));
+                }
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} failures:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn normalization_test_cases() {
+    check_conformance(normalize);
+}
+
+#[test]
+fn normalization_test_cases_streamed() {
+    check_conformance(normalize_streamed);
+}
+
+/// The quick check must never contradict normalizing the text.
+#[test]
+fn is_normalized_agrees_with_normalize() {
+    let mut failures = Vec::new();
+
+    for case in test_cases() {
+        for form in FORMS {
+            for (column, text) in case.columns.iter().enumerate() {
+                let expected = normalize(text, form) == *text;
+
+                if is_normalized(text, form) != expected {
+                    failures.push(format!(
+                        "line {} ({}): c{} is {}in {form:?}",
+                        case.line,
+                        case.part,
+                        column + 1,
+                        if expected { "" } else { "not " },
+                    ));
+                }
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} failures:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+/// Every character which is not listed in part 1 is left alone by all of
+/// the forms.
+#[test]
+fn unlisted_characters_are_invariant() {
+    let listed: HashSet<char> = test_cases()
+        .into_iter()
+        .filter(|case| case.part.starts_with("Part1"))
+        .map(|case| case.columns[0].chars().next().unwrap())
+        .collect();
+
+    let mut failures = Vec::new();
+
+    for ch in (0..=char::MAX as u32).filter_map(char::from_u32) {
+        if listed.contains(&ch) {
+            continue;
+        }
+
+        let text = ch.to_string();
+
+        for form in FORMS {
+            let actual = normalize(&text, form);
+
+            if actual != text {
+                failures.push(format!(
+                    "{form:?} of {:04X} is [{}]",
+                    ch as u32,
+                    codepoints(&actual)
+                ));
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} failures:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn nfkc_casefold_mappings() {
+    assert_eq!(nfkc_casefold("ＡＢＣ", false), "abc");
+    assert_eq!(nfkc_casefold("\u{FB01}", false), "fi");
+    assert_eq!(nfkc_casefold("Straße", false), "strasse");
+    // the simple folding keeps ß, and folds ẞ to it
+    assert_eq!(nfkc_casefold("Straße", true), "straße");
+    assert_eq!(nfkc_casefold("\u{1E9E}", true), "ß");
+    assert_eq!(nfkc_casefold("\u{1E9E}", false), "ss");
+    // default ignorable code points are dropped
+    assert_eq!(nfkc_casefold("a\u{AD}b\u{200B}", false), "ab");
+    // the result is in NFC
+    assert_eq!(nfkc_casefold("E\u{301}", false), "é");
+    assert_eq!(nfkc_casefold("\u{212B}", false), "å");
+}
+
+/// Changes_When_NFKC_Casefolded is defined in `DerivedNormalizationProps.txt`
+/// as `cp != NFKC_Casefold(cp)`, and folding twice must change nothing.
+#[test]
+fn nfkc_casefold_agrees_with_changes_when_nfkc_casefolded() {
+    let (_, changes) = binary_properties()
+        .find(|(name, _)| *name == "Changes_When_NFKC_Casefolded")
+        .unwrap();
+    let mut failures = Vec::new();
+
+    for ch in (0..=char::MAX as u32).filter_map(char::from_u32) {
+        let text = ch.to_string();
+        let folded = nfkc_casefold(&text, false);
+
+        if (folded != text) != changes.contains(ch as u32) {
+            failures.push(format!(
+                "{:04X} folds to [{}]",
+                ch as u32,
+                codepoints(&folded)
+            ));
+        }
+
+        if nfkc_casefold(&folded, false) != folded {
+            failures.push(format!("{:04X} folds again", ch as u32));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} failures:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,159 @@
+//! Conformance tests for segmentation, driven by the `auxiliary/*Test.txt`
+//! files from the UCD.
+//!
+//! Each line of those files lists the code points of a string, with `÷`
+//! between two of them where there is a boundary and `×` where there isn't.
+
+use nu_plugin_unicode::unicode::{
+    line_break::line_breaks,
+    segmentation::{
+        grapheme::graphemes,
+        sentence::{SentenceSplitter, sentence_boundaries, sentences},
+        word::word_boundaries,
+    },
+};
+
+struct TestCase {
+    line: usize,
+    text: String,
+    /// The byte offsets of the `÷` markers.
+    boundaries: Vec<usize>,
+}
+
+fn test_cases(file: &str) -> Vec<TestCase> {
+    let path = format!(
+        "{}/crates/nu_plugin_unicode_ucd/ucd/auxiliary/{file}",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let mut cases = Vec::new();
+
+    for (idx, line) in std::fs::read_to_string(path).unwrap().lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut text = String::new();
+        let mut boundaries = Vec::new();
+
+        for token in line.split_whitespace() {
+            match token {
+                "÷" => boundaries.push(text.len()),
+                "×" => {}
+                cp => text.push(char::from_u32(u32::from_str_radix(cp, 16).unwrap()).unwrap()),
+            }
+        }
+
+        cases.push(TestCase {
+            line: idx + 1,
+            text,
+            boundaries,
+        });
+    }
+
+    cases
+}
+
+fn codepoints(text: &str) -> String {
+    text.chars()
+        .map(|ch| format!("{:04X}", ch as u32))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Turns a list of segments back into the offsets of the boundaries
+/// between them.
+fn segment_boundaries<'a>(segments: impl Iterator<Item = (usize, &'a str)>) -> Vec<usize> {
+    let mut boundaries = vec![0];
+
+    for (offset, segment) in segments {
+        boundaries.push(offset + segment.len());
+    }
+
+    boundaries
+}
+
+fn check_conformance(file: &str, boundaries: impl Fn(&str) -> Vec<usize>) {
+    let mut failures = Vec::new();
+    let cases = test_cases(file);
+
+    assert!(!cases.is_empty(), "no test cases in {file}");
+
+    for case in cases {
+        let actual = boundaries(&case.text);
+
+        if actual != case.boundaries {
+            failures.push(format!(
+                "line {}: [{}] has boundaries at {actual:?}, expected {:?}",
+                case.line,
+                codepoints(&case.text),
+                case.boundaries,
+            ));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} failures:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+/// Splits the text into sentences the way `unicode sentences` does for a
+/// byte stream, with each character arriving in a chunk of its own.
+fn sentence_boundaries_streamed(text: &str) -> Vec<usize> {
+    let mut boundaries = vec![0];
+    let mut splitter = SentenceSplitter::new();
+    let mut offset = 0;
+
+    let chunks = text
+        .chars()
+        .map(|ch| splitter.push(ch.encode_utf8(&mut [0; 4])))
+        .collect::<Vec<_>>();
+
+    for settled in chunks.into_iter().chain([splitter.finish()]) {
+        for (start, sentence) in sentences(&settled) {
+            boundaries.push(offset + start + sentence.len());
+        }
+
+        offset += settled.len();
+    }
+
+    boundaries
+}
+
+#[test]
+fn grapheme_break_test_cases() {
+    check_conformance("GraphemeBreakTest.txt", |text| {
+        segment_boundaries(graphemes(text))
+    });
+}
+
+#[test]
+fn word_break_test_cases() {
+    check_conformance("WordBreakTest.txt", word_boundaries);
+}
+
+#[test]
+fn sentence_break_test_cases() {
+    check_conformance("SentenceBreakTest.txt", sentence_boundaries);
+}
+
+#[test]
+fn sentence_break_test_cases_streamed() {
+    check_conformance("SentenceBreakTest.txt", sentence_boundaries_streamed);
+}
+
+/// Line breaks are never allowed at the start of the text, so the test
+/// cases start with `×` rather than `÷`.
+#[test]
+fn line_break_test_cases() {
+    check_conformance("LineBreakTest.txt", |text| {
+        line_breaks(text)
+            .into_iter()
+            .map(|(offset, _)| offset)
+            .collect()
+    });
+}